@@ -0,0 +1,165 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use lib::days::{self, Options};
+use lib::input::Source;
+use lib::parallel;
+use lib::solution::Times;
+
+const USAGE: &str = "Usage:
+    aoc list                         List implemented days
+    aoc run <day> [<input>|-]        Solve a single day
+    aoc run all                      Solve every implemented day (several
+                                     at once if built with `parallel`)
+    aoc bench <day>|all [<runs>]     Solve days <runs> times (default 10)
+                                     and report how long they took
+
+Options:
+    --example    Use the example from the puzzle text
+    --time       Report how long parsing and each part took
+    --<name>[=<value>]
+                 A parameter for the day.  Parameters for inputs/N.txt
+                 are also read from inputs/N.params";
+
+const BENCH_RUNS: usize = 10;
+
+fn list() {
+    for (day, _) in days::DAYS {
+        let status = |source: Source| {
+            if source.exists() {
+                source.to_string()
+            } else {
+                format!("{source} (missing)")
+            }
+        };
+        println!(
+            "Day {day:2}  {}  {}",
+            status(Source::puzzle(*day)),
+            status(Source::example(*day))
+        );
+    }
+}
+
+fn run(day: u8, options: &Options) -> Result<(), String> {
+    days::get(day).ok_or(format!("Day {day} is not implemented."))?;
+    println!("━━ Day {day} ━━");
+    days::run(day, options).map_err(|e| e.to_string())
+}
+
+/// Options for every day: the same flags, with the example if
+/// `--example` is given and the puzzle input otherwise.
+fn all_options(flags: &[&str]) -> Result<impl Fn(u8) -> Options, String> {
+    if let Some(path) = flags.iter().find(|flag| !flag.starts_with("--")) {
+        return Err(format!("Can't use the same input for every day: {path}"));
+    }
+    let options = Options::from_args(0, flags)?;
+    let example = flags.contains(&"--example");
+    Ok(move |day| Options {
+        source: if example {
+            Source::example(day)
+        } else {
+            Source::puzzle(day)
+        },
+        ..options.clone()
+    })
+}
+
+/// Solve every day, possibly several at once, and print the answers in
+/// order.
+fn run_all(flags: &[&str]) -> Result<(), String> {
+    let options = all_options(flags)?;
+    let reports = parallel::map(days::DAYS, |(day, _)| {
+        days::report(*day, &options(*day)).unwrap_or_else(|e| format!("{e}\n"))
+    });
+    for ((day, _), report) in days::DAYS.iter().zip(reports) {
+        println!("━━ Day {day} ━━");
+        print!("{report}");
+    }
+    Ok(())
+}
+
+/// Minimum, median and maximum of some durations.
+fn stats(mut durations: Vec<Duration>) -> String {
+    durations.sort();
+    format!(
+        "{:>10.2?} {:>10.2?} {:>10.2?}",
+        durations[0],
+        durations[durations.len() / 2],
+        durations[durations.len() - 1]
+    )
+}
+
+fn bench(day: u8, options: &Options, runs: usize) -> Result<(), String> {
+    let solver = days::get(day).ok_or(format!("Day {day} is not implemented."))?;
+    let input = options.source.read().map_err(|e| e.to_string())?;
+    let params = options.params().map_err(|e| e.to_string())?;
+    let times = (0..runs)
+        .map(|_| solver(&input, &params).map(|solved| solved.times))
+        .collect::<Result<Vec<Times>, _>>()
+        .map_err(|e| e.in_file(&options.source.to_string()).to_string())?;
+
+    println!("━━ Day {day} ━━ ({runs} runs)");
+    println!("{:8} {:>10} {:>10} {:>10}", "", "min", "median", "max");
+    println!("{:8} {}", "parse", stats(times.iter().map(|t| t.parse).collect()));
+    println!("{:8} {}", "part 1", stats(times.iter().map(|t| t.part1).collect()));
+    println!("{:8} {}", "part 2", stats(times.iter().map(|t| t.part2).collect()));
+    println!("{:8} {}", "total", stats(times.iter().map(Times::total).collect()));
+    Ok(())
+}
+
+/// Split the number of runs off the benchmark arguments.
+fn bench_runs<'a>(args: &[&'a str]) -> Result<(usize, Vec<&'a str>), String> {
+    match args {
+        [runs, rest @ ..] if !runs.starts_with('-') => runs
+            .parse()
+            .ok()
+            .filter(|runs| *runs > 0)
+            .map(|runs| (runs, rest.to_vec()))
+            .ok_or(format!("Not a number of runs: {runs}")),
+        _ => Ok((BENCH_RUNS, args.to_vec())),
+    }
+}
+
+fn bench_all(args: &[&str]) -> Result<(), String> {
+    let (runs, flags) = bench_runs(args)?;
+    let options = all_options(&flags)?;
+    for (day, _) in days::DAYS {
+        if let Err(e) = bench(*day, &options(*day), runs) {
+            println!("{e}");
+        }
+    }
+    Ok(())
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse().map_err(|_| format!("Not a day: {day}"))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args[..] {
+        ["list"] => {
+            list();
+            Ok(())
+        }
+        ["run", "all", ref flags @ ..] => run_all(flags),
+        ["run", day, ref rest @ ..] => parse_day(day).and_then(|day| {
+            Options::from_args(day, rest).and_then(|options| run(day, &options))
+        }),
+        ["bench", "all", ref rest @ ..] => bench_all(rest),
+        ["bench", day, ref rest @ ..] => parse_day(day).and_then(|day| {
+            let (runs, rest) = bench_runs(rest)?;
+            Options::from_args(day, &rest).and_then(|options| bench(day, &options, runs))
+        }),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
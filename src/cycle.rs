@@ -0,0 +1,201 @@
+//! Finding cycles in sequences, to skip ahead to far away steps.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cycle in a sequence: the element at `start + len` is the same as
+/// the one at `start`, and so on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The step before the cycle, or inside the first round of it,
+    /// that is in the same state as `step`.
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.len
+        }
+    }
+
+    /// Given `values[n]`, some quantity at step `n` that grows by the
+    /// same amount every time around the cycle, compute its value at
+    /// step `target`.  `values` must go at least to `start + len`.
+    pub fn extrapolate(&self, values: &[i64], target: usize) -> i64 {
+        if target < values.len() {
+            return values[target];
+        }
+        let per_cycle = values[self.start + self.len] - values[self.start];
+        let cycles = ((target - self.start) / self.len) as i64;
+        values[self.reduce(target)] + cycles * per_cycle
+    }
+}
+
+/// Floyd's tortoise and hare, on the sequence `x0`, `f(x0)`,
+/// `f(f(x0))`…  The sequence must be eventually periodic.
+pub fn floyd<S: PartialEq>(x0: S, f: impl Fn(&S) -> S) -> Cycle {
+    let mut tortoise = f(&x0);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&f(&hare));
+    }
+
+    let mut start = 0;
+    let mut tortoise = x0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    let mut len = 1;
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        len += 1;
+    }
+    Cycle { start, len }
+}
+
+/// Brent's algorithm: same as [`floyd`], with fewer calls to `f`.
+pub fn brent<S: PartialEq + Clone>(x0: S, f: impl Fn(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = f(&hare);
+        len += 1;
+    }
+
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..len {
+        hare = f(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+    Cycle { start, len }
+}
+
+/// Finds a cycle in a sequence of states fed one at a time, by
+/// remembering at which step each state was seen.  Useful when the
+/// state is only part of what evolves, or expensive to recompute.
+#[derive(Clone, Debug)]
+pub struct Detector<K> {
+    seen: HashMap<K, usize>,
+    step: usize,
+}
+
+impl<K: Eq + Hash> Detector<K> {
+    pub fn new() -> Self {
+        Detector {
+            seen: HashMap::new(),
+            step: 0,
+        }
+    }
+
+    /// Record the state at the next step (starting at 0), and return
+    /// the cycle if it was already seen.
+    pub fn push(&mut self, state: K) -> Option<Cycle> {
+        let step = self.step;
+        self.step += 1;
+        let start = *self.seen.entry(state).or_insert(step);
+        (start != step).then_some(Cycle {
+            start,
+            len: step - start,
+        })
+    }
+}
+
+impl<K: Eq + Hash> Default for Detector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, then 4 to 8 over and over.
+    fn with_tail(x: &u32) -> u32 {
+        if *x == 8 {
+            4
+        } else {
+            x + 1
+        }
+    }
+
+    /// 0 to 6 over and over.
+    fn pure(x: &u32) -> u32 {
+        (x + 1) % 7
+    }
+
+    fn detect(x0: u32, f: impl Fn(&u32) -> u32) -> Cycle {
+        let mut detector = Detector::new();
+        let mut x = x0;
+        loop {
+            if let Some(cycle) = detector.push(x) {
+                return cycle;
+            }
+            x = f(&x);
+        }
+    }
+
+    #[test]
+    fn tail() {
+        let expected = Cycle { start: 4, len: 5 };
+        assert_eq!(floyd(0, with_tail), expected);
+        assert_eq!(brent(0, with_tail), expected);
+        assert_eq!(detect(0, with_tail), expected);
+    }
+
+    #[test]
+    fn no_tail() {
+        let expected = Cycle { start: 0, len: 7 };
+        assert_eq!(floyd(0, pure), expected);
+        assert_eq!(brent(0, pure), expected);
+        assert_eq!(detect(0, pure), expected);
+    }
+
+    #[test]
+    fn detectors_agree() {
+        // x² + 1 modulo some numbers, from every start.
+        for m in [2, 7, 10, 31, 100, 257] {
+            for x0 in 0..m {
+                let f = |x: &u32| (x * x + 1) % m;
+                let cycle = floyd(x0, f);
+                assert_eq!(brent(x0, f), cycle, "x0 = {x0}, m = {m}");
+                assert_eq!(detect(x0, f), cycle, "x0 = {x0}, m = {m}");
+            }
+        }
+    }
+
+    #[test]
+    fn reduce_and_extrapolate() {
+        let cycle = Cycle { start: 2, len: 3 };
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(5), 2);
+        assert_eq!(cycle.reduce(10), 4);
+        // Grows by 10 per cycle, after a tail.
+        let values = [0, 1, 5, 7, 8, 15];
+        assert_eq!(cycle.extrapolate(&values, 4), 8);
+        assert_eq!(cycle.extrapolate(&values, 8), 25);
+        assert_eq!(cycle.extrapolate(&values, 10), 28);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() -> std::process::ExitCode {
+    lib::days::main(24)
+}
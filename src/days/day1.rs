@@ -0,0 +1,35 @@
+use crate::solution::*;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    /// Calories carried by each elf, best first.
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut elves: Vec<u32> = vec![0];
+        for (n, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                elves.push(0);
+            } else {
+                *elves.last_mut().unwrap() += line
+                    .parse::<u32>()
+                    .map_err(|_| Error::parse(n + 1, line, "a number of calories"))?;
+            }
+        }
+
+        if elves.len() < 3 {
+            return Err(end_error(input, "at least three elves"));
+        }
+        elves.sort_by(|a, b| b.cmp(a));
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> Answer {
+        elves[0].into()
+    }
+
+    fn part2(elves: &Self::Input) -> Answer {
+        elves[0..3].iter().sum::<u32>().into()
+    }
+}
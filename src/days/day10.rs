@@ -0,0 +1,87 @@
+use crate::solution::*;
+use sscanf::sscanf;
+
+#[derive(Debug)]
+struct Device {
+    cycle: i64,
+    x: i64,
+    crt: String,
+
+    counter: i64,
+    next_event: i64
+}
+
+impl Device {
+    fn new() -> Device {
+        Device { cycle: 0,
+                 x: 1,
+                 crt: String::new(),
+                 counter: 0,
+                 next_event: 20
+        }
+    }
+
+    fn cycle(&mut self, incr: i64) {
+        // Part 2
+        let pixel = self.cycle % 40;
+        if pixel >= self.x -1 && pixel <= self.x + 1 {
+            self.crt.push('█');
+        } else {
+            self.crt.push(' ');
+        }
+        if (self.cycle + 1) % 40 == 0 {
+            self.crt.push('\n');
+        }
+
+        self.cycle += 1;
+        // Part 1
+        if self.cycle >= self.next_event {
+            self.counter += self.cycle * self.x;
+            self.next_event += 40;
+        }
+        self.x += incr;
+    }
+
+    fn run(program: &[Option<i64>]) -> Device {
+        let mut device = Device::new();
+        for instr in program {
+            device.cycle(0);
+
+            if let Some(incr) = instr {
+                device.cycle(*incr);
+            }
+        }
+        device
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    /// The program, as a list of `addx` increments (`None` is a `noop`)
+    type Input = Vec<Option<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, "`noop` or `addx n`", |line| {
+            if line == "noop" {
+                Some(None)
+            } else {
+                sscanf!(line, "addx {i64}").ok().map(Some)
+            }
+        })
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        Device::run(program).counter.into()
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        Device::run(program)
+            .crt
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<&str>>()
+            .join("\n")
+            .into()
+    }
+}
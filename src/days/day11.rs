@@ -0,0 +1,177 @@
+use crate::num::checked_lcm;
+use crate::solution::*;
+use sscanf::sscanf;
+use std::str::FromStr;
+
+type MonkeyId = usize;
+type Item = u64;
+type Level = u64;
+type Monkeys = Vec<Monkey>;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Op {
+    Add,
+    Mul,
+    Pow,
+}
+
+impl FromStr for Op {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Op::Add),
+            "*" => Ok(Op::Mul),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Monkey {
+    items: Vec<Item>,
+    op: Op,
+    op_constant: Level,
+    div_test: Level,
+    throw_to: (MonkeyId, MonkeyId),
+    counter: u64,
+}
+
+fn read_input(s: &str) -> Result<Monkeys> {
+    let mut lines = Lines::new(s);
+    let mut monkeys: Monkeys = Monkeys::new();
+    // Monkeys can throw to those described further down.
+    let count = s.lines().filter(|line| line.starts_with("Monkey ")).count();
+    loop {
+        let id = monkeys.len();
+        lines.parse(&format!("`Monkey {id}:`"), |line| {
+            sscanf!(line, "Monkey {MonkeyId}:")
+                .ok()
+                .filter(|n| *n == id)
+        })?;
+
+        let items = lines.parse("`  Starting items: …`", |line| {
+            sscanf!(line, "  Starting items: {str}")
+                .ok()?
+                .split(", ")
+                .map(|c| c.parse::<Item>().ok())
+                .collect::<Option<Vec<Item>>>()
+        })?;
+
+        let (mut op, c) = lines.parse("`  Operation: new = old <op> <operand>`", |line| {
+            let (op, c) = sscanf!(line, "  Operation: new = old {str} {str}").ok()?;
+            Some((op.parse::<Op>().ok()?, c))
+        })?;
+
+        let op_constant: Level;
+        if let Ok(c) = c.parse::<Level>() {
+            op_constant = c;
+        } else if c == "old" && op == Op::Mul {
+            op = Op::Pow;
+            op_constant = 2;
+        } else {
+            return Err(lines.error(c, "a number, or `old * old`"));
+        };
+
+        let div_test = lines.parse("`  Test: divisible by n`, with n above 0", |line| {
+            sscanf!(line, "  Test: divisible by {Level}")
+                .ok()
+                .filter(|n| *n > 0)
+        })?;
+
+        let to_monkey = |to: MonkeyId| (to < count).then_some(to);
+        let throw_to = (
+            lines.parse(
+                &format!("`    If true: throw to monkey n`, with n below {count}"),
+                |line| to_monkey(sscanf!(line, "    If true: throw to monkey {MonkeyId}").ok()?),
+            )?,
+            lines.parse(
+                &format!("`    If false: throw to monkey n`, with n below {count}"),
+                |line| to_monkey(sscanf!(line, "    If false: throw to monkey {MonkeyId}").ok()?),
+            )?,
+        );
+
+        let monkey = Monkey {
+            // id,
+            items,
+            op,
+            op_constant,
+            div_test,
+            throw_to,
+            counter: 0,
+        };
+        // println!("{:?}", &monkey);
+        monkeys.push(monkey);
+        if lines.next().is_none() {
+            break;
+        }
+    }
+    if monkeys.len() < 2 {
+        return Err(end_error(s, "a second monkey"));
+    }
+    Ok(monkeys)
+}
+
+/// Play `rounds` rounds of keep-away, and return the product of the
+/// two highest inspection counts.  If `relief` is set, worry levels
+/// are divided by three after each inspection (part 1).  `None` if
+/// worry levels overflow.
+fn monkey_business(monkeys: &Monkeys, rounds: usize, relief: bool) -> Option<u64> {
+    let mut monkeys = monkeys.clone();
+    let mut throws: Vec<Vec<Item>> = monkeys.iter().map(|_| vec![]).collect();
+
+    // Worry levels only matter modulo each monkey's test.
+    let divs = monkeys
+        .iter()
+        .try_fold(1, |divs, m| checked_lcm(divs, m.div_test))?;
+
+    for _ in 0..rounds {
+        for (id, monkey) in monkeys.iter_mut().enumerate() {
+            monkey.items.append(&mut throws[id]);
+            for object in monkey.items.drain(0..monkey.items.len()) {
+                // println!("Monkey {} inspects an item with worry level {}", id, object);
+                monkey.counter += 1;
+                // Worry level changes during inspection.
+                let mut level = match monkey.op {
+                    Op::Add => object.checked_add(monkey.op_constant),
+                    Op::Mul => object.checked_mul(monkey.op_constant),
+                    Op::Pow => object.checked_pow(monkey.op_constant as u32),
+                }?;
+
+                if relief {
+                    level /= 3;
+                } else {
+                    level %= divs;
+                }
+
+                throws[if level % monkey.div_test == 0 {
+                    monkey.throw_to.0
+                } else {
+                    monkey.throw_to.1
+                }]
+                    .push(level);
+            }
+        }
+    }
+    let mut counts = monkeys.iter().map(|m| m.counter).collect::<Vec<u64>>();
+    counts.sort_by(|a, b| b.cmp(a));
+    counts[0].checked_mul(counts[1])
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Monkeys;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        monkey_business(monkeys, 20, true).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(monkeys: &Self::Input) -> Answer {
+        monkey_business(monkeys, 10000, false).map_or(Answer::Unsolved, Answer::from)
+    }
+}
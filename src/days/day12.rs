@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+use crate::geom::Point2;
+use crate::matrix::*;
+use crate::search::{bfs, Paths};
+use crate::solution::*;
+
+type Maze = Matrix<Point>;
+
+#[derive(Copy, Clone)]
+pub enum Point {
+    Goal,
+    Start,
+    Elevation(u8),
+}
+
+impl Point {
+    fn from_char(c: char) -> Option<Point> {
+        match c {
+            'E' => Some(Point::Goal),
+            'S' => Some(Point::Start),
+            'a'..='z' => Some(Point::Elevation(c as u8 - b'a')),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    fn is_start(&self) -> bool {
+        matches!(self, Self::Start)
+    }
+
+    #[must_use]
+    fn is_goal(&self) -> bool {
+        matches!(self, Self::Goal)
+    }
+
+    fn elevation(&self) -> u8 {
+        match self {
+            Point::Goal => 25,
+            Point::Start => 0,
+            Point::Elevation(e) => *e,
+        }
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Point::Goal => 'E',
+                Point::Start => 'S',
+                Point::Elevation(c) => (c + b'a') as char,
+            }
+        )
+    }
+}
+
+fn find(maze: &Maze, pred: impl Fn(&Point) -> bool) -> Option<Coord> {
+    maze.vec.iter().position(pred).map(|idx| maze.to_coords(idx))
+}
+
+/// Points from which one can climb to `center`.
+fn neighbours(maze: &Maze, center: Coord) -> impl Iterator<Item = Coord> + '_ {
+    let elev = maze[center].elevation();
+    maze.neighbours4(center)
+        .filter(move |(_, p)| p.elevation() + 1 >= elev)
+        .map(|(coords, _)| coords)
+}
+
+/// Compute the distance of every point to the goal.
+fn explore(maze: &Maze) -> Paths<Coord, usize> {
+    bfs(find(maze, Point::is_goal), |point| neighbours(maze, point))
+}
+
+/// Draw the maze, with a shortest path from `point` to the goal.
+fn draw_path(maze: &Maze, paths: &Paths<Coord, usize>, point: Coord) -> String {
+    let path = paths.path(&point).unwrap_or_default();
+    let mut ret = String::new();
+    for y in 0..maze.height() as isize {
+        for x in 0..maze.width() as isize {
+            if path.contains(&Point2::new(x, y)) {
+                ret.push('█');
+            } else {
+                ret += &maze[(x, y)].to_string();
+            }
+        }
+        ret.push('\n');
+    }
+    ret
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Maze;
+
+    const PARAMS: &'static [&'static str] = &["path"];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Matrix::from_str_with(input, |c| Point::from_char(c).ok_or(c))
+            .map_err(|(c, _)| grid_error(input, c.y, "a row of elevations"))
+    }
+
+    fn part1(maze: &Self::Input) -> Answer {
+        find(maze, Point::is_start)
+            .and_then(|start| explore(maze).distance(&start))
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(maze: &Self::Input) -> Answer {
+        let paths = explore(maze);
+        paths
+            .dist
+            .iter()
+            .filter(|(coords, _)| maze[**coords].elevation() == 0)
+            .map(|(_, dist)| *dist)
+            .min()
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    /// With `--path`, the maze with a shortest path from the start.
+    fn show(maze: &Self::Input, params: &Params) -> Result<Option<String>> {
+        let start = find(maze, Point::is_start);
+        Ok(start
+            .filter(|_| params.flag("path"))
+            .map(|start| draw_path(maze, &explore(maze), start)))
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use crate::geom::*;
+use crate::matrix::*;
+use crate::sparse_grid::SparseGrid;
+use crate::solution::*;
+use sscanf::sscanf;
+
+const SOURCE: Coord = Point2::new(500, 0);
+
+/// Rock and sand; the rest is air.
+#[derive(Clone)]
+pub struct Cave {
+    cave: SparseGrid<Element>,
+    floor: isize,
+}
+
+#[derive(Eq, PartialEq, PartialOrd, Ord, Copy, Clone, Debug)]
+enum Element {
+    Sand,
+    Air,
+    Rock,
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Element::Sand => "o",
+                Element::Air => ".",
+                Element::Rock => "#",
+            }
+        )
+    }
+}
+
+fn read_input(s: &str) -> Result<Cave> {
+    let mut cave = SparseGrid::new();
+    let mut floor: isize = 0;
+
+    for (n, line) in s.lines().enumerate() {
+        let mut start: Option<Coord> = None;
+        for pair in line.split(" -> ") {
+            let end: Coord = sscanf!(pair, "{isize},{isize}")
+                .ok()
+                .filter(|(_, y)| *y >= 0)
+                .ok_or_else(|| Error::parse(n + 1, line, "a path of `x,y` points below the source"))?
+                .into();
+            // Update floor
+            if end.y > floor - 2 {
+                floor = end.y + 2;
+            }
+
+            if let Some(start) = start {
+                if start.x != end.x && start.y != end.y {
+                    return Err(Error::parse(n + 1, line, "horizontal or vertical segments"));
+                }
+                let step = (end - start).signum();
+                let mut pos = start;
+                cave.insert(pos, Element::Rock);
+                while pos != end {
+                    pos += step;
+                    cave.insert(pos, Element::Rock);
+                }
+            }
+            // Proceed
+            start = Some(end);
+        }
+    }
+    Ok(Cave { cave, floor })
+}
+
+/// Drop a unit of sand, and return whether it came to rest.  Without
+/// a floor, sand that goes below every rock falls forever.
+fn add_sand(cave: &mut Cave, has_floor: bool) -> bool {
+    let mut pos = SOURCE;
+    if cave.cave.contains(pos) {
+        return false;
+    }
+
+    'outer: loop {
+        let below = pos.step(Heading::Down);
+        if below.y < cave.floor {
+            for cand in [below, below.step(Heading::Left), below.step(Heading::Right)] {
+                if !cave.cave.contains(cand) {
+                    pos = cand;
+                    continue 'outer;
+                }
+            }
+        } else if !has_floor {
+            return false;
+        }
+        // No candidate
+        cave.cave.insert(pos, Element::Sand);
+        return true;
+    }
+}
+
+/// Pour sand until it stops coming to rest, and return how many units
+/// did, with the cave then.
+fn pour(cave: &Cave, has_floor: bool) -> (usize, Cave) {
+    let mut cave = cave.clone();
+    let mut counter = 0;
+    while add_sand(&mut cave, has_floor) {
+        counter += 1;
+    }
+    (counter, cave)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+
+    const PARAMS: &'static [&'static str] = &["sand"];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    fn part1(cave: &Self::Input) -> Answer {
+        pour(cave, false).0.into()
+    }
+
+    fn part2(cave: &Self::Input) -> Answer {
+        pour(cave, true).0.into()
+    }
+
+    /// With `--sand=1` or `--sand=2`, the cave once sand stops coming
+    /// to rest in that part.
+    fn show(cave: &Self::Input, params: &Params) -> Result<Option<String>> {
+        if !params.flag("sand") {
+            return Ok(None);
+        }
+        let has_floor = match params.get("sand", 0)? {
+            1 => false,
+            2 => true,
+            part => {
+                return Err(Error::Param(format!(
+                    "Bad value for --sand: `{part}`, expected 1 or 2"
+                )))
+            }
+        };
+        let (_, cave) = pour(cave, has_floor);
+        let (matrix, _) = cave.cave.to_matrix(Element::Air);
+        Ok(Some(format!("{}\n", matrix.to_string().trim_start())))
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use crate::geom::Point2;
+use crate::intervals::RangeSet;
+use crate::solution::*;
+use sscanf::sscanf;
+
+type Coord = Point2<i64>;
+
+#[derive(Debug, Hash)]
+pub struct Sensor {
+    pos: Coord,
+    beacon: Coord,
+    dist: i64,
+}
+
+/// The sensors, the row to look at in part 1, and the largest
+/// coordinate of the search area of part 2.
+pub struct Scan {
+    sensors: Vec<Sensor>,
+    row: i64,
+    bound: i64,
+}
+
+fn read_input(s: &str) -> Result<Vec<Sensor>> {
+    parse_lines(s, "`Sensor at x=…, y=…: closest beacon is at x=…, y=…`", |line| {
+        let (sx, sy, bx, by) = sscanf!(
+            line,
+            "Sensor at x={i64}, y={i64}: closest beacon is at x={i64}, y={i64}"
+        )
+            .ok()?;
+        let (pos, beacon) = (Point2::new(sx, sy), Point2::new(bx, by));
+        Some(Sensor {
+            pos,
+            beacon,
+            dist: pos.manhattan(beacon),
+        })
+    })
+}
+
+/// The positions of row `y` that are in range of a sensor.
+fn coverage(sensors: &[Sensor], y: i64) -> RangeSet {
+    sensors
+        .iter()
+        .filter_map(|s| {
+            let reach = s.dist - (s.pos.y - y).abs();
+            (reach >= 0).then(|| s.pos.x - reach..s.pos.x + reach + 1)
+        })
+        .collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Scan;
+
+    const PARAMS: &'static [&'static str] = &["row", "bound"];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        Ok(Scan {
+            sensors: read_input(input)?,
+            row: params.get("row", 2_000_000)?,
+            bound: params.get("bound", 4_000_000)?,
+        })
+    }
+
+    /// Positions of the row where a beacon cannot be present.
+    fn part1(scan: &Self::Input) -> Answer {
+        // Known beacons in range don't count.
+        let beacons = scan
+            .sensors
+            .iter()
+            .map(|s| s.beacon)
+            .filter(|b| b.y == scan.row)
+            .collect::<HashSet<Coord>>()
+            .len() as i64;
+        (coverage(&scan.sensors, scan.row).len() - beacons).into()
+    }
+
+    /// The tuning frequency of the distress beacon, the only position
+    /// of the search area out of range of every sensor.
+    fn part2(scan: &Self::Input) -> Answer {
+        (0..=scan.bound)
+            .find_map(|y| {
+                let mut gaps = coverage(&scan.sensors, y).gaps(0..scan.bound + 1);
+                gaps.next().map(|gap| gap.start * 4_000_000 + y)
+            })
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+}
@@ -0,0 +1,138 @@
+// The tunnels themselves don't matter, only how long it takes to go
+// from a valve worth opening to another.  So the network is first
+// reduced to those valves (and the start), with the shortest distance
+// between each pair.
+//
+// Then a depth first search tries every order of opening valves while
+// time allows, and records the best pressure released for each set of
+// opened valves.  One agent alone takes the best of all sets; two
+// agents take the best pair of disjoint sets.
+
+use std::collections::HashMap;
+
+use crate::search::bfs;
+use crate::solution::*;
+use sscanf::sscanf;
+
+type Pressure = u32;
+type Minutes = u32;
+/// A set of valves worth opening, as a bitmask of their indices.
+type Valves = usize;
+
+const START: &str = "AA";
+
+#[derive(Debug)]
+pub struct Valve {
+    name: String,
+    flow: Pressure,
+    tunnels: Vec<String>,
+}
+
+/// The valves with some flow, and the start, which comes last.
+pub struct Network {
+    flows: Vec<Pressure>,
+    // Minutes to walk from a valve to another.
+    dist: Vec<Vec<Minutes>>,
+}
+
+impl Network {
+    fn new(valves: &[Valve]) -> Option<Network> {
+        let index: HashMap<&str, usize> =
+            valves.iter().enumerate().map(|(i, v)| (v.name.as_str(), i)).collect();
+        let mut useful: Vec<usize> = (0..valves.len()).filter(|i| valves[*i].flow > 0).collect();
+        useful.push(*index.get(START)?);
+
+        let dist = useful
+            .iter()
+            .map(|from| {
+                let paths = bfs([*from], |valve| {
+                    valves[valve].tunnels.iter().filter_map(|name| index.get(name.as_str()).copied())
+                });
+                useful
+                    .iter()
+                    .map(|to| paths.distance(to).map_or(Minutes::MAX, |d| d as Minutes))
+                    .collect()
+            })
+            .collect();
+        Some(Network {
+            flows: useful.iter().map(|i| valves[*i].flow).collect(),
+            dist,
+        })
+    }
+
+    /// Number of valves worth opening.
+    fn len(&self) -> usize {
+        self.flows.len() - 1
+    }
+
+    /// The most pressure one agent can release in `time` minutes by
+    /// opening exactly each set of valves (0 for the sets it can't
+    /// open in time).
+    fn best_per_set(&self, time: Minutes) -> Vec<Pressure> {
+        let mut best = vec![0; 1 << self.len()];
+        self.explore(self.len(), time, 0, 0, &mut best);
+        best
+    }
+
+    fn explore(&self, at: usize, time: Minutes, opened: Valves, released: Pressure, best: &mut [Pressure]) {
+        best[opened] = best[opened].max(released);
+        for next in (0..self.len()).filter(|next| opened & 1 << next == 0) {
+            // Walk there, then open it.
+            let cost = self.dist[at][next].saturating_add(1);
+            if cost < time {
+                let left = time - cost;
+                self.explore(next, left, opened | 1 << next, released + self.flows[next] * left, best);
+            }
+        }
+    }
+}
+
+fn read_input(s: &str) -> Result<Vec<Valve>> {
+    parse_lines(s, "`Valve XX has flow rate=n; tunnels lead to valves YY, ZZ`", |line| {
+        let (name, flow, rest) = sscanf!(line, "Valve {String} has flow rate={Pressure}; {str}").ok()?;
+        let tunnels = rest
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| rest.strip_prefix("tunnel leads to valve "))?;
+        Some(Valve {
+            name,
+            flow,
+            tunnels: tunnels.split(", ").map(String::from).collect(),
+        })
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let valves = read_input(input)?;
+        Network::new(&valves).ok_or_else(|| end_error(input, "a valve named AA"))
+    }
+
+    /// Most pressure released alone in 30 minutes.
+    fn part1(network: &Self::Input) -> Answer {
+        network.best_per_set(30).into_iter().max().unwrap_or(0).into()
+    }
+
+    /// Most pressure released with an elephant in 26 minutes.  Each
+    /// opens its own set of valves.
+    fn part2(network: &Self::Input) -> Answer {
+        let mut best = network.best_per_set(26);
+        // Make best[set] the best over all subsets of set.
+        for valve in 0..network.len() {
+            for set in 0..best.len() {
+                if set & 1 << valve != 0 {
+                    best[set] = best[set].max(best[set ^ 1 << valve]);
+                }
+            }
+        }
+        let all = best.len() - 1;
+        (0..best.len())
+            .map(|set| best[set] + best[all ^ set])
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+
+use crate::cycle::Detector;
+use crate::solution::*;
+
+const CHAMBER_WIDTH: usize = 7;
+const FULL_ROW: u8 = (1 << CHAMBER_WIDTH) - 1;
+
+/// A rock, as rows of bits from the bottom up.  Bit `x` is column `x`
+/// from the left, so pushing a rock right is shifting it left.
+#[derive(Clone, Copy)]
+struct Shape {
+    rows: &'static [u8],
+    width: usize,
+}
+
+const SHAPES: [Shape; 5] = [
+    // ####
+    Shape { rows: &[0b1111], width: 4 },
+    // .#.
+    // ###
+    // .#.
+    Shape { rows: &[0b010, 0b111, 0b010], width: 3 },
+    // ..#
+    // ..#
+    // ###
+    Shape { rows: &[0b111, 0b100, 0b100], width: 3 },
+    // #
+    // #
+    // #
+    // #
+    Shape { rows: &[0b1, 0b1, 0b1, 0b1], width: 1 },
+    // ##
+    // ##
+    Shape { rows: &[0b11, 0b11], width: 2 },
+];
+
+// The chamber in which the rocks fall only keeps the rows the next
+// rocks can still reach.  Every time a rock comes to rest, we look
+// down from the top for the lowest row an empty cell can be reached
+// from above, moving sideways or down like a rock.  Everything below
+// is dropped, and only counted in the height of the tower:
+//
+// |..##...|
+// |..#.#..|
+// |#.#.#..|
+// |#####..| <<<<< lowest reachable row
+// |.#####.|  dropped
+// |...#...|  dropped
+//
+// Rows are numbered from the bottom of the chamber, dropped rows
+// included.
+
+#[derive(Clone)]
+struct Chamber {
+    // The kept rows, from the bottom up.  The top row isn't empty.
+    rows: VecDeque<u8>,
+    // How many rows were dropped below the kept ones.
+    dropped: usize,
+}
+
+impl Chamber {
+    fn new() -> Chamber {
+        Chamber {
+            rows: VecDeque::new(),
+            dropped: 0,
+        }
+    }
+
+    /// Height of the tower.
+    fn height(&self) -> usize {
+        self.dropped + self.rows.len()
+    }
+
+    /// Whether `shape` fits with its lower left corner at (x,y).
+    /// Dropped rows count as full.
+    fn can_fit(&self, shape: Shape, x: isize, y: usize) -> bool {
+        if x < 0 || x as usize + shape.width > CHAMBER_WIDTH || y < self.dropped {
+            return false;
+        }
+        shape.rows.iter().enumerate().all(|(dy, row)| {
+            let kept = self.rows.get(y + dy - self.dropped).copied().unwrap_or(0);
+            kept & (row << x) == 0
+        })
+    }
+
+    fn add_shape(&mut self, shape: Shape, x: isize, y: usize) {
+        for (dy, row) in shape.rows.iter().enumerate() {
+            let idx = y + dy - self.dropped;
+            if idx >= self.rows.len() {
+                self.rows.resize(idx + 1, 0);
+            }
+            self.rows[idx] |= row << x;
+        }
+        self.drop_unreachable();
+    }
+
+    /// Drop the rows below the lowest one a falling rock could reach.
+    fn drop_unreachable(&mut self) {
+        // Cells reachable in the row above, starting with the empty row
+        // above the tower.
+        let mut reach = FULL_ROW;
+        let mut lowest = self.rows.len();
+        for (y, row) in self.rows.iter().enumerate().rev() {
+            let free = !row & FULL_ROW;
+            let mut cur = reach & free;
+            loop {
+                let next = (cur | cur << 1 | cur >> 1) & free;
+                if next == cur {
+                    break;
+                }
+                cur = next;
+            }
+            if cur == 0 {
+                break;
+            }
+            lowest = y;
+            reach = cur;
+        }
+        self.rows.drain(..lowest);
+        self.dropped += lowest;
+    }
+
+    #[allow(dead_code)]
+    fn draw(&self) {
+        println!("           ┏━━━━━━━┓");
+        for (y, row) in self.rows.iter().enumerate().rev() {
+            let cells: String = (0..CHAMBER_WIDTH)
+                .map(|x| if row & 1 << x != 0 { '#' } else { '.' })
+                .collect();
+            println!("{:10} ┃{cells}┃", y + self.dropped);
+        }
+        println!("           ┗━━━━━━━┛");
+    }
+}
+
+/// Everything that decides how the next rocks fall: the next shape,
+/// the next jet, and the reachable top of the tower.
+type State = (usize, usize, Vec<u8>);
+
+/// Rocks falling in a chamber, one at a time.
+struct Tower<'a> {
+    chamber: Chamber,
+    jets: &'a [isize],
+    jet: usize,
+    rocks: usize,
+}
+
+impl<'a> Tower<'a> {
+    fn new(jets: &'a [isize]) -> Self {
+        Tower {
+            chamber: Chamber::new(),
+            jets,
+            jet: 0,
+            rocks: 0,
+        }
+    }
+
+    fn height(&self) -> i64 {
+        self.chamber.height() as i64
+    }
+
+    fn state(&self) -> State {
+        (
+            self.rocks % SHAPES.len(),
+            self.jet,
+            self.chamber.rows.iter().copied().collect(),
+        )
+    }
+
+    fn drop_rock(&mut self) {
+        let shape = SHAPES[self.rocks % SHAPES.len()];
+        self.rocks += 1;
+        let mut x: isize = 2;
+        let mut y = self.chamber.height() + 3;
+        loop {
+            // Descent
+            let jet = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            if self.chamber.can_fit(shape, x + jet, y) {
+                x += jet;
+            }
+
+            // let mut show = self.chamber.clone();
+            // show.add_shape(shape, x, y);
+            // show.draw();
+
+            if y > 0 && self.chamber.can_fit(shape, x, y - 1) {
+                y -= 1;
+            } else {
+                self.chamber.add_shape(shape, x, y);
+                break;
+            }
+        }
+    }
+}
+
+/// Height of the tower after `rocks` rocks fell in a fresh chamber.
+/// The tower is only simulated until its state repeats, and the rest
+/// is extrapolated.
+fn simulate(jets: &[isize], rocks: usize) -> i64 {
+    let mut tower = Tower::new(jets);
+    let mut detector = Detector::new();
+    let mut heights = vec![];
+
+    loop {
+        heights.push(tower.height());
+        if heights.len() > rocks {
+            return heights[rocks];
+        }
+        if let Some(cycle) = detector.push(tower.state()) {
+            return cycle.extrapolate(&heights, rocks);
+        }
+        tower.drop_rock();
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Lines::new(input).parse("a pattern of `<` and `>`", |line| {
+            line.chars()
+                .map(|c| match c {
+                    '<' => Some(-1),
+                    '>' => Some(1),
+                    _ => None,
+                })
+                .collect::<Option<Vec<isize>>>()
+                .filter(|jets| !jets.is_empty())
+        })
+    }
+
+    fn part1(jets: &Self::Input) -> Answer {
+        simulate(jets, 2022).into()
+    }
+
+    fn part2(jets: &Self::Input) -> Answer {
+        simulate(jets, 1_000_000_000_000).into()
+    }
+}
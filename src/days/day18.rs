@@ -0,0 +1,34 @@
+use crate::geom::Point3;
+use crate::solution::*;
+use sscanf::sscanf;
+
+type Coord3 = Point3<isize>;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Coord3>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, "`x,y,z`", |line| {
+            sscanf!(line, "{isize},{isize},{isize}").ok().map(Point3::from)
+        })
+    }
+
+    /// Surface area of the droplet.
+    fn part1(input: &Self::Input) -> Answer {
+        let mut count = 0;
+        for a in 0..input.len() {
+            for b in a..input.len() {
+                if input[a].manhattan(input[b]) == 1 {
+                    count += 1;
+                }
+            }
+        }
+        (input.len()*6 - count*2).into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,264 @@
+// Depth first search over the robots to build, one after the other.
+//
+// Instead of going minute by minute, each step of the search picks the
+// next robot to build, and skips ahead to the minute it's done.  Paths
+// are cut when:
+//
+//  - even building a geode robot every remaining minute couldn't beat
+//    the best solution found so far;
+//  - we already have as many robots of a kind as we could ever spend
+//    of its resource in a minute (never for geode robots);
+//  - the factory was already in the same state with the same time
+//    left.
+
+use std::collections::HashSet;
+
+use crate::parallel;
+use crate::solution::*;
+use sscanf::sscanf;
+use std::fmt::Debug;
+
+type Ore = usize;
+type Clay = usize;
+type Obsidian = usize;
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+struct Factory {
+    time_left: usize,
+
+    ore_store: Ore,
+    clay_store: Clay,
+    obsidian_store: Obsidian,
+
+    ore_robots: usize,
+    clay_robots: usize,
+    obsidian_robots: usize,
+    geode_robots: usize,
+
+    geodes: usize,
+}
+
+/// The best number of geodes found so far, and the factory states
+/// already explored.
+struct Search<'a> {
+    blueprint: &'a Blueprint,
+    best: usize,
+    seen: HashSet<Factory>,
+}
+
+impl Factory {
+    fn new(time: usize) -> Factory {
+        Factory {
+            time_left: time,
+
+            ore_store: 0,
+            clay_store: 0,
+            obsidian_store: 0,
+            geodes: 0,
+
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        }
+    }
+
+    /// Geodes at the end of time if no more robots are built.
+    fn idle_geodes(&self) -> usize {
+        self.geodes + self.geode_robots * self.time_left
+    }
+
+    /// Geodes at the end of time if a geode robot was built every
+    /// minute from now on.
+    fn upper_bound(&self) -> usize {
+        let t = self.time_left;
+        self.idle_geodes() + t * t.saturating_sub(1) / 2
+    }
+
+    /// Minutes to wait until `cost` can be paid from `store`, with
+    /// `robots` mining more each minute.
+    fn wait(cost: usize, store: usize, robots: usize) -> Option<usize> {
+        if store >= cost {
+            Some(0)
+        } else if robots == 0 {
+            None
+        } else {
+            Some((cost - store).div_ceil(robots))
+        }
+    }
+
+    /// The factory after waiting `minutes` and then spending another
+    /// minute building a robot, which isn't counted yet.
+    fn advance(&self, minutes: usize, ore: Ore, clay: Clay, obsidian: Obsidian) -> Factory {
+        let minutes = minutes + 1;
+        Factory {
+            time_left: self.time_left - minutes,
+            ore_store: self.ore_store + self.ore_robots * minutes - ore,
+            clay_store: self.clay_store + self.clay_robots * minutes - clay,
+            obsidian_store: self.obsidian_store + self.obsidian_robots * minutes - obsidian,
+            geodes: self.geodes + self.geode_robots * minutes,
+            ..*self
+        }
+    }
+
+    /// Drop the resources that can't be spent in the time left, so that
+    /// states differing only by those are the same.
+    fn normalize(mut self, blueprint: &Blueprint) -> Factory {
+        let t = self.time_left;
+        let cap = |robots: usize, max: usize| max * t - robots * t.saturating_sub(1);
+        self.ore_store = self.ore_store.min(cap(self.ore_robots, blueprint.max_ore));
+        self.clay_store = self.clay_store.min(cap(self.clay_robots, blueprint.obsidian_cost.1));
+        self.obsidian_store = self
+            .obsidian_store
+            .min(cap(self.obsidian_robots, blueprint.geode_cost.1));
+        self
+    }
+
+    /// The factories after building each kind of robot next, best
+    /// bets first.
+    fn next_builds(&self, blueprint: &Blueprint) -> Vec<Factory> {
+        let mut ret = Vec::with_capacity(4);
+        let mut build = |wait: Option<usize>, ore, clay, obsidian, add: fn(&mut Factory)| {
+            if let Some(wait) = wait.filter(|w| w + 1 < self.time_left) {
+                let mut new = self.advance(wait, ore, clay, obsidian);
+                add(&mut new);
+                ret.push(new.normalize(blueprint));
+            }
+        };
+
+        let (ore, obsidian) = blueprint.geode_cost;
+        let wait = Factory::wait(ore, self.ore_store, self.ore_robots)
+            .zip(Factory::wait(obsidian, self.obsidian_store, self.obsidian_robots))
+            .map(|(a, b)| a.max(b));
+        build(wait, ore, 0, obsidian, |f| f.geode_robots += 1);
+
+        if self.obsidian_robots < blueprint.geode_cost.1 {
+            let (ore, clay) = blueprint.obsidian_cost;
+            let wait = Factory::wait(ore, self.ore_store, self.ore_robots)
+                .zip(Factory::wait(clay, self.clay_store, self.clay_robots))
+                .map(|(a, b)| a.max(b));
+            build(wait, ore, clay, 0, |f| f.obsidian_robots += 1);
+        }
+
+        if self.clay_robots < blueprint.obsidian_cost.1 {
+            let ore = blueprint.clay_cost;
+            let wait = Factory::wait(ore, self.ore_store, self.ore_robots);
+            build(wait, ore, 0, 0, |f| f.clay_robots += 1);
+        }
+
+        if self.ore_robots < blueprint.max_ore {
+            let ore = blueprint.ore_cost;
+            let wait = Factory::wait(ore, self.ore_store, self.ore_robots);
+            build(wait, ore, 0, 0, |f| f.ore_robots += 1);
+        }
+        ret
+    }
+}
+
+impl Search<'_> {
+    fn explore(&mut self, factory: Factory) {
+        self.best = self.best.max(factory.idle_geodes());
+        if factory.upper_bound() <= self.best || !self.seen.insert(factory) {
+            return;
+        }
+        for next in factory.next_builds(self.blueprint) {
+            self.explore(next);
+        }
+    }
+}
+
+/// Most geodes that can be opened with a blueprint in `time` minutes.
+fn break_geodes(blueprint: &Blueprint, time: usize) -> usize {
+    let mut search = Search {
+        blueprint,
+        best: 0,
+        seen: HashSet::new(),
+    };
+    search.explore(Factory::new(time));
+    search.best
+}
+
+impl Debug for Factory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:2}: (Store: ore={:2} c={:2} obs={:2} g={:?}) (Robots: ore={:?} c={:?} obs={:?} g={:?})",
+            self.time_left,
+            self.ore_store,
+            self.clay_store,
+            self.obsidian_store,
+            self.geodes,
+            self.ore_robots,
+            self.clay_robots,
+            self.obsidian_robots,
+            self.geode_robots,
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Blueprint {
+    id: usize,
+    ore_cost: Ore,
+    clay_cost: Ore,
+    obsidian_cost: (Ore, Clay),
+    geode_cost: (Ore, Obsidian),
+    // Most ore any robot costs.
+    max_ore: Ore,
+}
+
+fn read_input(s: &str) -> Result<Vec<Blueprint>> {
+    parse_lines(s, "`Blueprint n: Each ore robot costs …`", |line| {
+        let
+            (id, ore_cost, clay_cost, obsidian_cost_ore, obsidian_cost_clay, geode_cost_ore, geode_cost_obsidian) =
+            sscanf!(line,
+                    "Blueprint {usize}: Each ore robot costs {usize} ore. Each clay robot costs {usize} ore. Each obsidian robot costs {usize} ore and {usize} clay. Each geode robot costs {usize} ore and {usize} obsidian.").ok()?;
+        Some(Blueprint {
+            id,
+            ore_cost,
+            clay_cost,
+            obsidian_cost: (obsidian_cost_ore, obsidian_cost_clay),
+            geode_cost: (geode_cost_ore, geode_cost_obsidian),
+            max_ore: ore_cost.max(clay_cost).max(obsidian_cost_ore).max(geode_cost_ore),
+        })
+    })
+}
+
+fn part1(blueprints: &[Blueprint]) -> usize {
+    let time = 24;
+    let geodes = parallel::map(blueprints, |blueprint| break_geodes(blueprint, time));
+    let mut result = 0;
+    for (blueprint, geodes) in blueprints.iter().zip(geodes) {
+        result += blueprint.id * geodes;
+    }
+    result
+}
+
+fn part2(blueprints: &[Blueprint]) -> usize {
+    let time = 32;
+    let blueprints = &blueprints[..blueprints.len().min(3)];
+    parallel::map(blueprints, |blueprint| break_geodes(blueprint, time))
+        .into_iter()
+        .product()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    /// Sum of the quality levels of all blueprints.
+    fn part1(blueprints: &Self::Input) -> Answer {
+        part1(blueprints).into()
+    }
+
+    /// Product of the geodes of the first three blueprints, with more
+    /// time.
+    fn part2(blueprints: &Self::Input) -> Answer {
+        part2(blueprints).into()
+    }
+}
@@ -0,0 +1,108 @@
+use crate::solution::*;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Outcome {
+    Victory,
+    Defeat,
+    Draw,
+}
+
+
+fn read_move(c: &char) -> Option<Move> {
+    use Move::*;
+    match c {
+        'A' => Some(Rock),
+        'B' => Some(Paper),
+        'C' => Some(Scissors),
+        'X' => Some(Rock),
+        'Y' => Some(Paper),
+        'Z' => Some(Scissors),
+        _ => None, // You played Spock
+    }
+}
+
+fn read_outcome(c: &char) -> Option<Outcome> {
+    use Outcome::*;
+    match c {
+        'X' => Some(Defeat),
+        'Y' => Some(Draw),
+        'Z' => Some(Victory),
+        _ => None,
+    }
+}
+
+fn pick_move(their: &Move, outcome: &Outcome) -> Move {
+    use Move::*;
+    use Outcome::*;
+    match (their,outcome) {
+        (Rock, Victory) => Paper,
+        (Rock, Defeat) => Scissors,
+        (Paper, Victory) => Scissors,
+        (Paper, Defeat) => Rock,
+        (Scissors, Victory) => Rock,
+        (Scissors, Defeat) => Paper,
+        (x, Draw) => *x,
+    }
+}
+
+fn move_value(m: &Move)-> isize {
+    match m {
+        Move::Rock => 1,
+        Move::Paper => 2,
+        Move::Scissors => 3,
+    }
+}
+
+fn score(us: &Move, them: &Move) -> (isize,isize) {
+    use Move::*;
+    let result = match (us, them) {
+        (Rock, Paper) => (0, 6),
+        (Rock, Scissors) => (6,0),
+        (Paper, Rock) => (6,0),
+        (Paper, Scissors) => (0,6),
+        (Scissors, Rock)=> (0,6),
+        (Scissors, Paper) => (6,0),
+        _ => (3,3),
+    };
+    (move_value(us) + result.0, move_value(them) + result.1)
+}
+
+type Round = (Move, Move, Outcome);
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, "`A X` to `C Z`", |line| {
+            let their = read_move(&line.chars().next()?)?;
+            let ours = read_move(&line.chars().nth(2)?)?;
+            let outcome = read_outcome(&line.chars().nth(2)?)?;
+            Some((their, ours, outcome))
+        })
+    }
+
+    fn part1(rounds: &Self::Input) -> Answer {
+        rounds
+            .iter()
+            .map(|(their, ours, _)| score(ours, their).0)
+            .sum::<isize>()
+            .into()
+    }
+
+    fn part2(rounds: &Self::Input) -> Answer {
+        rounds
+            .iter()
+            .map(|(their, _, outcome)| score(&pick_move(their, outcome), their).0)
+            .sum::<isize>()
+            .into()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::solution::*;
+use crate::treap::IndexedList;
+
+/// Mix the numbers `repeat` times, and return the sum of the grove
+/// coordinates.  Each number moves by its value, around a circle that
+/// doesn't count the number itself.
+fn decrypt(numbers: &[i64], repeat: usize) -> i64 {
+    let mut list: IndexedList<i64> = numbers.iter().copied().collect();
+    let count = numbers.len() as i64;
+
+    for _ in 0..repeat {
+        // Ids are the original positions.
+        for id in 0..numbers.len() {
+            let index = list.index_of(id);
+            list.remove(index);
+            let new = (index as i64 + list.get(id)).rem_euclid(count - 1);
+            list.insert(new as usize, id);
+        }
+    }
+
+    let zero = numbers.iter().position(|n| *n == 0).unwrap_or_default();
+    let zero = list.index_of(zero);
+    (1..4)
+        .map(|i| *list.get(list.at((zero + i * 1000) % list.len())))
+        .sum()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let numbers = parse_lines(input, "a number", |l| l.parse::<i64>().ok())?;
+        if numbers.len() < 2 {
+            return Err(end_error(input, "at least two numbers"));
+        }
+        if !numbers.contains(&0) {
+            return Err(end_error(input, "a 0 among the numbers"));
+        }
+        Ok(numbers)
+    }
+
+    /// Grove location.
+    fn part1(input: &Self::Input) -> Answer {
+        decrypt(input, 1).into()
+    }
+
+    /// Grove location, with decryption key.
+    fn part2(input: &Self::Input) -> Answer {
+        let input: Vec<i64> = input.iter().map(|x| x * 811589153).collect();
+        decrypt(&input, 10).into()
+    }
+}
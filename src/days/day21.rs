@@ -0,0 +1,422 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    fs,
+    str::FromStr,
+};
+
+use crate::num::Rational;
+use crate::solution::*;
+use sscanf::sscanf;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Op {
+    Add,
+    Div,
+    Mul,
+    Sub,
+}
+
+impl FromStr for Op {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Op::*;
+        match s {
+            "*" => Ok(Mul),
+            "/" => Ok(Div),
+            "+" => Ok(Add),
+            "-" => Ok(Sub),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Op {
+    /// Operators of higher precedence bind tighter.
+    fn precedence(self) -> u8 {
+        match self {
+            Op::Add | Op::Sub => 1,
+            Op::Mul | Op::Div => 2,
+        }
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Mul => write!(f, "×"),
+            Op::Add => write!(f, "+"),
+            Op::Sub => write!(f, "-"),
+            Op::Div => write!(f, "/"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Monkey {
+    Literal(i128),
+    Ref(String),
+    Op(Op, Box<Monkey>, Box<Monkey>),
+    Human,
+}
+
+impl Monkey {
+    fn as_monkey_ref(&self) -> Option<&String> {
+        if let Self::Ref(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+}
+
+fn read_input(s: &str) -> Result<HashMap<String, Monkey>> {
+    let monkeys = parse_lines(s, "`name: number` or `name: name op name`", |line| {
+        if let Ok((name, number)) = sscanf!(line, "{String}: {i128}") {
+            Some((name, Monkey::Literal(number)))
+        } else if let Ok((name, left, op, right)) =
+            sscanf!(line, "{String}: {String} {String} {String}")
+        {
+            Some((
+                name,
+                Monkey::Op(
+                    op.parse().ok()?,
+                    Box::new(Monkey::Ref(left)),
+                    Box::new(Monkey::Ref(right)),
+                ),
+            ))
+        } else {
+            None
+        }
+    })?;
+
+    // Every monkey listened to must exist.
+    let names: HashSet<&String> = monkeys.iter().map(|(name, _)| name).collect();
+    for (n, (_, monkey)) in monkeys.iter().enumerate() {
+        if let Monkey::Op(_, left, right) = monkey {
+            for name in [left, right].into_iter().filter_map(|m| m.as_monkey_ref()) {
+                if !names.contains(name) {
+                    let line = s.lines().nth(n).unwrap_or_default();
+                    return Err(Error::parse(n + 1, line, &format!("a monkey named {name}")));
+                }
+            }
+        }
+    }
+    if !names.contains(&"root".to_string()) {
+        return Err(end_error(s, "a monkey named root"));
+    }
+    if let Some(n) = find_cycle(&monkeys) {
+        let line = s.lines().nth(n).unwrap_or_default();
+        return Err(Error::parse(
+            n + 1,
+            line,
+            "a monkey that doesn't listen to itself, even indirectly",
+        ));
+    }
+    Ok(monkeys.into_iter().collect())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    Unseen,
+    OnPath,
+    Done,
+}
+
+/// The index of a monkey that listens to itself through the others,
+/// if any.  Every name must exist.
+fn find_cycle(monkeys: &[(String, Monkey)]) -> Option<usize> {
+    fn visit(
+        n: usize,
+        monkeys: &[(String, Monkey)],
+        index: &HashMap<&str, usize>,
+        state: &mut [Visit],
+    ) -> Option<usize> {
+        match state[n] {
+            Visit::OnPath => return Some(n),
+            Visit::Done => return None,
+            Visit::Unseen => state[n] = Visit::OnPath,
+        }
+        if let Monkey::Op(_, left, right) = &monkeys[n].1 {
+            for name in [left, right].into_iter().filter_map(|m| m.as_monkey_ref()) {
+                if let Some(found) = visit(index[name.as_str()], monkeys, index, state) {
+                    return Some(found);
+                }
+            }
+        }
+        state[n] = Visit::Done;
+        None
+    }
+
+    let index: HashMap<&str, usize> = monkeys
+        .iter()
+        .enumerate()
+        .map(|(n, (name, _))| (name.as_str(), n))
+        .collect();
+    let mut state = vec![Visit::Unseen; monkeys.len()];
+    (0..monkeys.len()).find_map(|n| visit(n, monkeys, &index, &mut state))
+}
+
+enum Expression {
+    Literal(Rational),
+    Operation {
+        op: Op,
+        lhs: Box<Expression>,
+        rhs: Box<Expression>,
+    },
+    Human,
+}
+
+impl Expression {
+    /// The expression of the monkey `name`, with every operation on
+    /// known values folded.  `None` if folding overflows or divides by
+    /// zero.
+    fn new(monkeys: &HashMap<String, Monkey>, name: &str) -> Option<Self> {
+        Self::new_from_monkey(monkeys, monkeys.get(name)?)
+    }
+
+    fn new_from_monkey(monkeys: &HashMap<String, Monkey>, mnk: &Monkey) -> Option<Self> {
+        use Expression::*;
+        Some(match mnk {
+            Monkey::Literal(l) => Literal((*l).into()),
+            Monkey::Ref(name) => Self::new(monkeys, name)?,
+            Monkey::Op(op, left, right) => {
+                let lhs = Self::new_from_monkey(monkeys, left)?;
+                let rhs = Self::new_from_monkey(monkeys, right)?;
+                if let (Some(left), Some(right)) = (lhs.as_literal(), rhs.as_literal()) {
+                    Literal(match op {
+                        Op::Add => left.checked_add(right),
+                        Op::Sub => left.checked_sub(right),
+                        Op::Div => left.checked_div(right),
+                        Op::Mul => left.checked_mul(right),
+                    }?)
+                } else {
+                    Operation {
+                        op: *op,
+                        lhs: Box::new(lhs),
+                        rhs: Box::new(rhs),
+                    }
+                }
+            }
+            Monkey::Human => Human,
+        })
+    }
+
+    /// The value the human must yell for this expression to equal
+    /// `target`.  Once folded, only one side of each operation still
+    /// depends on the human: we undo the operations one by one on the
+    /// way down to it.
+    fn solve(&self, target: Rational) -> Option<Rational> {
+        use Expression::*;
+        match self {
+            Literal(_) => None,
+            Human => Some(target),
+            Operation { op, lhs, rhs } => match (lhs.as_literal(), rhs.as_literal()) {
+                // lhs op n = target
+                (None, Some(n)) => lhs.solve(match op {
+                    Op::Add => target.checked_sub(n),
+                    Op::Sub => target.checked_add(n),
+                    Op::Mul => target.checked_div(n),
+                    Op::Div => target.checked_mul(n),
+                }?),
+                // n op rhs = target
+                (Some(n), None) => rhs.solve(match op {
+                    Op::Add => target.checked_sub(n),
+                    Op::Sub => n.checked_sub(target),
+                    Op::Mul => target.checked_div(n),
+                    Op::Div => n.checked_div(target),
+                }?),
+                _ => None,
+            },
+        }
+    }
+
+    fn as_literal(&self) -> Option<Rational> {
+        if let Self::Literal(v) = self {
+            Some(*v)
+        } else {
+            None
+        }
+    }
+}
+
+impl Expression {
+    /// Write an operand of `op`, with parentheses only if they're
+    /// needed: around looser operations, and on the right of `-` and
+    /// `/` around operations as tight.
+    fn fmt_operand(&self, f: &mut std::fmt::Formatter<'_>, op: Op, right: bool) -> std::fmt::Result {
+        let parens = match self {
+            Expression::Operation { op: inner, .. } => {
+                inner.precedence() < op.precedence()
+                    || right && inner.precedence() == op.precedence() && matches!(op, Op::Sub | Op::Div)
+            }
+            _ => false,
+        };
+        if parens {
+            write!(f, "({self})")
+        } else {
+            write!(f, "{self}")
+        }
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Expression::*;
+        match self {
+            Literal(n) => n.fmt(f),
+            Operation { op, lhs, rhs } => {
+                lhs.fmt_operand(f, *op, false)?;
+                write!(f, " {op} ")?;
+                rhs.fmt_operand(f, *op, true)
+            }
+            Human => write!(f, "[H]"),
+        }
+    }
+}
+
+/// Both sides of `root`'s equality, once the human is in place.
+fn equation(monkeys: &HashMap<String, Monkey>) -> Option<(Expression, Expression)> {
+    let mut monkeys = monkeys.clone();
+    monkeys.insert("humn".to_string(), Monkey::Human);
+
+    if let Some(Monkey::Op(_, left, right)) = monkeys.get("root") {
+        let left = Expression::new(&monkeys, left.as_monkey_ref()?)?;
+        let right = Expression::new(&monkeys, right.as_monkey_ref()?)?;
+        Some((left, right))
+    } else {
+        None
+    }
+}
+
+/// What the human must yell for both sides of `root` to be equal.
+fn part2(monkeys: &HashMap<String, Monkey>) -> Option<i128> {
+    let (left, right) = equation(monkeys)?;
+    let human = match (left.as_literal(), right.as_literal()) {
+        (None, Some(target)) => left.solve(target),
+        (Some(target), None) => right.solve(target),
+        _ => None,
+    };
+    human?.to_integer()
+}
+
+/// The monkeys from `from` down to `to`, if `from` listens to `to`,
+/// even indirectly.
+fn path<'a>(monkeys: &'a HashMap<String, Monkey>, from: &'a str, to: &str) -> Option<Vec<&'a str>> {
+    if from == to {
+        return Some(vec![from]);
+    }
+    if let Some(Monkey::Op(_, left, right)) = monkeys.get(from) {
+        for next in [left, right] {
+            if let Some(mut path) = path(monkeys, next.as_monkey_ref()?, to) {
+                path.insert(0, from);
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+/// The monkeys as a Graphviz graph, each pointing to the monkeys it
+/// listens to.  The path from `root` to `humn` is in red, and the
+/// equation of part 2 is in a comment.
+fn to_dot(monkeys: &HashMap<String, Monkey>) -> String {
+    let path = path(monkeys, "root", "humn").unwrap_or_default();
+    let on_path = |name: &str| path.contains(&name);
+    let mut names: Vec<&String> = monkeys.keys().collect();
+    names.sort();
+
+    let mut dot = String::from("digraph monkeys {\n");
+    if let Some((left, right)) = equation(monkeys) {
+        dot += &format!("    // {left} = {right}\n");
+    }
+    for name in names {
+        let style = if on_path(name) { ", color=red, fontcolor=red" } else { "" };
+        match &monkeys[name] {
+            Monkey::Op(op, left, right) => {
+                dot += &format!("    {name} [label=\"{name}\\n{op}\"{style}];\n");
+                for next in [left, right].into_iter().filter_map(|m| m.as_monkey_ref()) {
+                    let style = if on_path(name) && on_path(next) {
+                        " [color=red, penwidth=2]"
+                    } else {
+                        ""
+                    };
+                    dot += &format!("    {name} -> {next}{style};\n");
+                }
+            }
+            Monkey::Literal(n) => {
+                dot += &format!("    {name} [label=\"{name}\\n{n}\", shape=box{style}];\n");
+            }
+            _ => {}
+        }
+    }
+    dot += "}\n";
+    dot
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Monkey>;
+
+    const PARAMS: &'static [&'static str] = &["dot", "equation"];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    /// What `root` yells.
+    fn part1(monkeys: &Self::Input) -> Answer {
+        Expression::new(monkeys, "root")
+            .and_then(|root| root.as_literal())
+            .and_then(Rational::to_integer)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    /// What the human must yell to pass `root`'s equality test.
+    fn part2(monkeys: &Self::Input) -> Answer {
+        part2(monkeys).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    /// With `--equation`, the equation of part 2.  With
+    /// `--dot=<path>`, the monkeys as a Graphviz graph, written to
+    /// `path` so that it can go straight to `dot`.
+    fn show(monkeys: &Self::Input, params: &Params) -> Result<Option<String>> {
+        if params.flag("dot") {
+            let path: String = params.get("dot", String::new())?;
+            if path.is_empty() {
+                return Err(Error::Param("--dot needs a file: --dot=<path>".to_string()));
+            }
+            fs::write(&path, to_dot(monkeys)).map_err(|e| Error::Io(path, e))?;
+        }
+        if params.flag("equation") {
+            if let Some((left, right)) = equation(monkeys) {
+                return Ok(Some(format!("{left} = {right}\n")));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dot_file_holds_only_the_graph() {
+        let path = std::env::temp_dir().join(format!("day21-{}.dot", std::process::id()));
+        let params = Params::from_args([format!("--dot={}", path.display()).as_str()]).unwrap();
+        let solved = solve::<Day21>(include_str!("../../inputs/21-example.txt"), &params).unwrap();
+        let dot = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(solved.shown, None);
+        assert!(dot.starts_with("digraph monkeys {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches("digraph").count(), 1);
+        assert_eq!(dot.matches('{').count(), 1);
+        assert_eq!(dot.matches('}').count(), 1);
+        assert!(dot.contains("root -> pppw [color=red, penwidth=2];"));
+    }
+}
@@ -0,0 +1,320 @@
+use crate::geom::*;
+use crate::matrix::*;
+use crate::solution::*;
+
+type Board = Matrix<Objects>;
+type Path = Vec<Step>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Objects {
+    Outside,
+    Tile,
+    Wall,
+}
+
+impl Objects {
+    fn from_char(c: char) -> Option<Objects> {
+        match c {
+            ' ' => Some(Objects::Outside),
+            '.' => Some(Objects::Tile),
+            '#' => Some(Objects::Wall),
+            _ => None,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Step {
+    Move(usize),
+    Left,
+    Right,
+}
+
+/// Return true if position is outside the map.  This doesn't
+/// guarantee you can wrap, since it doesn't check for walls.
+fn is_outside(board: &Board, pos: Coord) -> bool {
+    board.cell(pos).is_none_or(|cell| *cell == Objects::Outside)
+}
+
+/// Wrap around the flat map: from position pos going towards heading,
+/// come back from the other end of the row or column.
+fn flat_wrap(board: &Board, pos: Coord, heading: Heading) -> (Coord, Heading) {
+    let dest = board
+        .ray(pos, heading.reverse())
+        .take_while(|(_, cell)| **cell != Objects::Outside)
+        .last()
+        .map_or(pos, |(seek, _)| seek);
+    (dest, heading)
+}
+
+// Move player from pos to the next valid position towards heading,
+// using wrap to go over the edges of the map.  The heading can change
+// when wrapping.
+fn move1(
+    board: &Board,
+    pos: Coord,
+    heading: Heading,
+    wrap: impl Fn(Coord, Heading) -> (Coord, Heading),
+) -> (Coord, Heading) {
+    let (next, next_heading) = if is_outside(board, pos.step(heading)) {
+        wrap(pos, heading)
+    } else {
+        (pos.step(heading), heading)
+    };
+    match board[next] {
+        Objects::Tile => (next, next_heading),
+        Objects::Wall => (pos, heading),
+        Objects::Outside => panic!("Wrapped outside the map at {next}"),
+    }
+}
+
+// Folding the map into a cube.
+//
+// The map is a net of six N×N faces.  Each face gets a frame: the 3D
+// directions of going right and down on the map, and of its outward
+// normal.  The first face lies flat, then folding a face over the
+// edge of one we already placed gives its frame:
+//
+//     right neighbour: right' = -normal, down' = down,   normal' = right
+//     down neighbour:  right' = right,   down' = -normal, normal' = down
+//
+// (and the same, reversed, for left and up neighbours).  Going over an
+// edge of a face in 3D direction e leads to the face whose normal is
+// e, entering it going away from the first face, so in 3D direction
+// -normal.  The position along the edge, counted from the left of the
+// direction of travel, doesn't change.
+
+type Vector = Point3<i32>;
+
+#[derive(Clone, Copy, Debug)]
+struct Face {
+    // Top left corner on the map.
+    origin: Coord,
+    right: Vector,
+    down: Vector,
+    normal: Vector,
+}
+
+impl Face {
+    /// The 3D direction of a heading on the map.
+    fn direction(&self, heading: Heading) -> Vector {
+        match heading {
+            Heading::Right => self.right,
+            Heading::Down => self.down,
+            Heading::Left => -self.right,
+            Heading::Up => -self.down,
+        }
+    }
+
+    /// The face folded over the edge towards heading, at origin.
+    fn fold(&self, heading: Heading, origin: Coord) -> Face {
+        let (right, down, normal) = match heading {
+            Heading::Right => (-self.normal, self.down, self.right),
+            Heading::Left => (self.normal, self.down, -self.right),
+            Heading::Down => (self.right, -self.normal, self.down),
+            Heading::Up => (self.right, self.normal, -self.down),
+        };
+        Face {
+            origin,
+            right,
+            down,
+            normal,
+        }
+    }
+}
+
+struct Cube {
+    size: isize,
+    faces: Vec<Face>,
+    // For each face and heading, the face across the edge and the new
+    // heading.
+    edges: Vec<[(usize, Heading); 4]>,
+}
+
+impl Cube {
+    /// Fold the map, if it's the net of a cube.
+    fn fold(board: &Board) -> Option<Cube> {
+        let area = board.vec.iter().filter(|cell| **cell != Objects::Outside).count();
+        let size = (1..).find(|n| 6 * n * n >= area)? as isize;
+        if 6 * size * size != area as isize {
+            return None;
+        }
+        let is_face = |origin: Coord| !is_outside(board, origin);
+
+        let first = Point2::new(find_start(board).x / size * size, 0);
+        let mut faces = vec![Face {
+            origin: first,
+            right: Vector::new(1, 0, 0),
+            down: Vector::new(0, 1, 0),
+            normal: Vector::new(0, 0, -1),
+        }];
+        let mut todo = vec![0];
+        while let Some(idx) = todo.pop() {
+            let face = faces[idx];
+            for heading in Heading::ALL {
+                let origin = face.origin + heading.offset() * size;
+                if is_face(origin) && faces.iter().all(|f| f.origin != origin) {
+                    faces.push(face.fold(heading, origin));
+                    todo.push(faces.len() - 1);
+                }
+            }
+        }
+        if faces.len() != 6 {
+            return None;
+        }
+
+        let edges = faces
+            .iter()
+            .map(|face| {
+                Heading::ALL.map(|heading| {
+                    let edge = face.direction(heading);
+                    let next = faces.iter().position(|f| f.normal == edge)?;
+                    let next_heading = Heading::ALL
+                        .into_iter()
+                        .find(|h| faces[next].direction(*h) == -face.normal)?;
+                    Some((next, next_heading))
+                })
+            })
+            .map(|edges| {
+                let [a, b, c, d] = edges;
+                Some([a?, b?, c?, d?])
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Cube { size, faces, edges })
+    }
+
+    /// Position on the edge of a face towards heading, counted from
+    /// the left of heading.
+    fn along(&self, local: Coord, heading: Heading) -> isize {
+        let n = self.size - 1;
+        match heading {
+            Heading::Right => local.y,
+            Heading::Down => n - local.x,
+            Heading::Left => n - local.y,
+            Heading::Up => local.x,
+        }
+    }
+
+    /// The cell of a face at position k on its edge towards heading,
+    /// counted from the left of heading.
+    fn edge_cell(&self, heading: Heading, k: isize) -> Coord {
+        let n = self.size - 1;
+        match heading {
+            Heading::Right => Point2::new(n, k),
+            Heading::Down => Point2::new(n - k, n),
+            Heading::Left => Point2::new(0, n - k),
+            Heading::Up => Point2::new(k, 0),
+        }
+    }
+
+    /// Go over the edge of a face, from position pos going towards
+    /// heading.
+    fn wrap(&self, pos: Coord, heading: Heading) -> (Coord, Heading) {
+        let size = self.size;
+        let face = self
+            .faces
+            .iter()
+            .position(|f| f.origin == Point2::new(pos.x / size * size, pos.y / size * size))
+            .expect("Not on a face");
+        let k = self.along(pos - self.faces[face].origin, heading);
+        let (next, next_heading) = self.edges[face][heading as usize];
+        // Entering through the edge behind next_heading.  Looking
+        // towards that edge, left and right are swapped.
+        let local = self.edge_cell(next_heading.reverse(), size - 1 - k);
+        (self.faces[next].origin + local, next_heading)
+    }
+}
+
+fn read_input(s: &str) -> Result<(Board, Path)> {
+    let (map, rest) = s.split_once("\n\n").unwrap_or((s, ""));
+    let board = Matrix::from_str_with(map, |c| Objects::from_char(c).ok_or(c))
+        .map_err(|(c, _)| grid_error(map, c.y, "a row of ` `, `.` and `#`"))?;
+    if !board.vec.contains(&Objects::Tile) {
+        return Err(end_error(map, "a board with an open tile"));
+    }
+
+    let mut path: Vec<Step> = vec![];
+    let mut acc: usize = 0;
+    let line = rest.lines().next().unwrap_or_default();
+    let error = || Error::parse(map.lines().count() + 2, line, "a path of numbers, `L` and `R`");
+    for c in line.chars() {
+        if c.is_ascii_digit() {
+            acc *= 10;
+            acc += crate::read_digit(c) as usize;
+        } else if c == 'L' || c == 'R' {
+            path.push(Step::Move(acc));
+            path.push(if c == 'L' { Step::Left } else { Step::Right });
+            acc = 0;
+        } else {
+            return Err(error());
+        }
+    }
+    if acc > 0 {
+        path.push(Step::Move(acc));
+    }
+
+    Ok((board, path))
+}
+
+fn find_start(board: &Board) -> Coord {
+    let idx = board.vec.iter().position(|cell| *cell == Objects::Tile).unwrap();
+    board.to_coords(idx)
+}
+
+fn facing(heading: Heading) -> isize {
+    match heading {
+        Heading::Right => 0,
+        Heading::Down => 1,
+        Heading::Left => 2,
+        Heading::Up => 3,
+    }
+}
+
+/// Follow the path from the start, and return the final password.
+fn walk(
+    board: &Board,
+    path: &Path,
+    wrap: impl Fn(Coord, Heading) -> (Coord, Heading),
+) -> isize {
+    let mut player = find_start(board);
+    let mut heading = Heading::Right;
+
+    for step in path {
+        match step {
+            Step::Move(n) => {
+                for _ in 0..*n {
+                    (player, heading) = move1(board, player, heading, &wrap);
+                }
+            }
+            Step::Left => heading = heading.turn_left(),
+            Step::Right => heading = heading.turn_right(),
+        }
+    }
+    let row = player.y + 1;
+    let col = player.x + 1;
+    let facing = facing(heading);
+    row * 1000 + col * 4 + facing
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Board, Path);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    /// The final password.
+    fn part1((board, path): &Self::Input) -> Answer {
+        walk(board, path, |pos, heading| flat_wrap(board, pos, heading)).into()
+    }
+
+    /// The final password, on the folded cube.
+    fn part2((board, path): &Self::Input) -> Answer {
+        Cube::fold(board).map_or(Answer::Unsolved, |cube| {
+            walk(board, path, |pos, heading| cube.wrap(pos, heading)).into()
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use crate::geom::*;
+use crate::matrix::*;
+use crate::sparse_grid::SparseGrid;
+use crate::solution::*;
+
+type Elves = SparseGrid<()>;
+
+/// The directions elves consider, in order, on the first round.  The
+/// first one moves to the back every round.
+const ORDER: [Heading; 4] = [Heading::Up, Heading::Down, Heading::Left, Heading::Right];
+
+fn read_input(s: &str) -> Result<Elves> {
+    let mut elves = Elves::new();
+    for (y, line) in s.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '#' => {
+                    elves.insert(Point2::new(x as isize, y as isize), ());
+                }
+                '.' => {}
+                _ => return Err(grid_error(s, y as isize, "a row of `.` and `#`")),
+            }
+        }
+    }
+    Ok(elves)
+}
+
+/// Where an elf wants to go on the given round, if anywhere.
+fn proposal(elves: &Elves, elf: Coord, round: usize) -> Option<Coord> {
+    // Alone, the elf stays put.
+    elves.neighbours8(elf).next()?;
+    (0..4).map(|i| ORDER[(round + i) % 4]).find_map(|heading| {
+        let next = elf.step(heading);
+        let free = [next, next.step(heading.turn_left()), next.step(heading.turn_right())]
+            .iter()
+            .all(|c| !elves.contains(*c));
+        free.then_some(next)
+    })
+}
+
+/// Play a round (counted from 0), and return whether any elf moved.
+fn play_round(elves: &mut Elves, round: usize) -> bool {
+    let proposals: Vec<(Coord, Option<Coord>)> = elves
+        .iter()
+        .map(|(elf, _)| (elf, proposal(elves, elf, round)))
+        .collect();
+    let mut counts: HashMap<Coord, usize> = HashMap::new();
+    for dest in proposals.iter().filter_map(|(_, dest)| *dest) {
+        *counts.entry(dest).or_default() += 1;
+    }
+
+    let mut moved = false;
+    let mut next = Elves::new();
+    for (elf, dest) in proposals {
+        match dest {
+            Some(dest) if counts[&dest] == 1 => {
+                moved = true;
+                next.insert(dest, ());
+            }
+            _ => {
+                next.insert(elf, ());
+            }
+        }
+    }
+    *elves = next;
+    moved
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Elves;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    /// Empty ground in the smallest rectangle around the elves, after
+    /// ten rounds.
+    fn part1(elves: &Self::Input) -> Answer {
+        let mut elves = elves.clone();
+        for round in 0..10 {
+            play_round(&mut elves, round);
+        }
+        (elves.width() * elves.height() - elves.len()).into()
+    }
+
+    /// The first round where no elf moves.
+    fn part2(elves: &Self::Input) -> Answer {
+        let mut elves = elves.clone();
+        let mut round = 0;
+        while play_round(&mut elves, round) {
+            round += 1;
+        }
+        (round + 1).into()
+    }
+}
@@ -0,0 +1,220 @@
+// Blizzards come back to where they started every `width` minutes for
+// horizontal ones, and every `height` minutes for vertical ones.  So
+// the whole basin repeats every lcm(width, height) minutes, and the
+// state of a search is just a position and a minute within that
+// period.
+//
+// Coordinates are inside the walls: the entrance is at y = -1 and the
+// exit at y = height.
+
+use crate::geom::*;
+use crate::matrix::*;
+use crate::num::lcm;
+use crate::search::astar;
+use crate::solution::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    Ground,
+    Blizzard(Heading),
+}
+
+impl Cell {
+    fn from_char(c: char) -> Option<Cell> {
+        match c {
+            '#' => Some(Cell::Wall),
+            '.' => Some(Cell::Ground),
+            '>' => Some(Cell::Blizzard(Heading::Right)),
+            '<' => Some(Cell::Blizzard(Heading::Left)),
+            '^' => Some(Cell::Blizzard(Heading::Up)),
+            'v' => Some(Cell::Blizzard(Heading::Down)),
+            _ => None,
+        }
+    }
+}
+
+fn arrow(heading: Heading) -> char {
+    match heading {
+        Heading::Right => '>',
+        Heading::Left => '<',
+        Heading::Up => '^',
+        Heading::Down => 'v',
+    }
+}
+
+pub struct Valley {
+    width: isize,
+    height: isize,
+    entrance: Coord,
+    exit: Coord,
+    // Blizzards going along each row, and along each column, with
+    // their position at minute 0 on that line.
+    rows: Vec<Vec<(isize, Heading)>>,
+    columns: Vec<Vec<(isize, Heading)>>,
+    // Where the blizzards are, for each minute of the period.
+    occupied: Vec<Matrix<bool>>,
+}
+
+impl Valley {
+    fn period(&self) -> usize {
+        self.occupied.len()
+    }
+
+    /// The blizzards and their positions at minute t.
+    fn blizzards(&self, t: usize) -> impl Iterator<Item = (Coord, Heading)> + '_ {
+        let t = t as isize;
+        let along = move |start: isize, heading: Heading, len: isize| {
+            let offset: Coord = heading.offset();
+            (start + (offset.x + offset.y) * t).rem_euclid(len)
+        };
+        let horizontal = self.rows.iter().enumerate().flat_map(move |(y, line)| {
+            line.iter()
+                .map(move |(x, h)| (Point2::new(along(*x, *h, self.width), y as isize), *h))
+        });
+        let vertical = self.columns.iter().enumerate().flat_map(move |(x, line)| {
+            line.iter()
+                .map(move |(y, h)| (Point2::new(x as isize, along(*y, *h, self.height)), *h))
+        });
+        horizontal.chain(vertical)
+    }
+
+    fn is_free(&self, pos: Coord, t: usize) -> bool {
+        pos == self.entrance
+            || pos == self.exit
+            || self.occupied[t % self.period()]
+                .cell(pos)
+                .is_some_and(|occupied| !occupied)
+    }
+
+    /// The minute of arrival at `to`, leaving `from` at minute `start`.
+    fn trip(&self, from: Coord, to: Coord, start: usize) -> Option<usize> {
+        let period = self.period();
+        let (paths, end) = astar(
+            [(from, start % period)],
+            |(pos, _)| *pos == to,
+            |(pos, t)| {
+                let t = (t + 1) % period;
+                // Wait, or move.
+                std::iter::once(pos)
+                    .chain(pos.neighbours4())
+                    .filter(move |next| self.is_free(*next, t))
+                    .map(move |next| ((next, t), 1))
+            },
+            |(pos, _)| pos.manhattan(to) as usize,
+        );
+        Some(start + paths.distance(&end?)?)
+    }
+
+    /// The basin at minute t, drawn like in the puzzle.
+    fn draw(&self, t: usize) -> String {
+        let mut cells = Matrix::new_default(self.width as usize, self.height as usize, '.');
+        for (pos, heading) in self.blizzards(t) {
+            cells[pos] = match cells[pos] {
+                '.' => arrow(heading),
+                c @ '2'..='8' => (c as u8 + 1) as char,
+                _ => '2',
+            };
+        }
+        let wall = |gap: Coord| {
+            (-1..=self.width)
+                .map(|x| if x == gap.x { '.' } else { '#' })
+                .collect::<String>()
+        };
+        let mut ret = wall(self.entrance) + "\n";
+        for row in cells.rows() {
+            ret += &format!("#{}#\n", row.iter().collect::<String>());
+        }
+        ret + &wall(self.exit) + "\n"
+    }
+}
+
+fn read_input(s: &str) -> Result<Valley> {
+    let map = Matrix::from_str_with(s, |c| Cell::from_char(c).ok_or(c))
+        .map_err(|(c, _)| grid_error(s, c.y, "a row of `#`, `.`, `>`, `<`, `^` and `v`"))?;
+    let (width, height) = (map.width() as isize - 2, map.height() as isize - 2);
+    if width < 1 || height < 1 {
+        return Err(grid_error(s, 0, "a valley inside walls"));
+    }
+
+    // The gap in the top and bottom walls.
+    let gap = |y: isize| {
+        let x = map.row(y).iter().position(|c| *c == Cell::Ground);
+        x.map(|x| Point2::new(x as isize - 1, y - 1))
+            .ok_or_else(|| grid_error(s, y, "a wall with one gap"))
+    };
+    let entrance = gap(0)?;
+    let exit = gap(height + 1)?;
+
+    let mut rows = vec![vec![]; height as usize];
+    let mut columns = vec![vec![]; width as usize];
+    for y in 0..height {
+        for x in 0..width {
+            match map[(x + 1, y + 1)] {
+                Cell::Blizzard(h @ (Heading::Left | Heading::Right)) => rows[y as usize].push((x, h)),
+                Cell::Blizzard(h) => columns[x as usize].push((y, h)),
+                Cell::Ground => {}
+                Cell::Wall => return Err(grid_error(s, y + 1, "no wall inside the valley")),
+            }
+        }
+    }
+
+    let mut valley = Valley {
+        width,
+        height,
+        entrance,
+        exit,
+        rows,
+        columns,
+        occupied: vec![],
+    };
+    let period = lcm(width, height) as usize;
+    valley.occupied = (0..period)
+        .map(|t| {
+            let mut occupied = Matrix::new_default(width as usize, height as usize, false);
+            for (pos, _) in valley.blizzards(t) {
+                occupied[pos] = true;
+            }
+            occupied
+        })
+        .collect();
+    Ok(valley)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+
+    const PARAMS: &'static [&'static str] = &["minute"];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    /// Fewest minutes to cross the valley.
+    fn part1(valley: &Self::Input) -> Answer {
+        valley
+            .trip(valley.entrance, valley.exit, 0)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    /// Fewest minutes to cross, go back for the snacks, and cross again.
+    fn part2(valley: &Self::Input) -> Answer {
+        let (entrance, exit) = (valley.entrance, valley.exit);
+        valley
+            .trip(entrance, exit, 0)
+            .and_then(|t| valley.trip(exit, entrance, t))
+            .and_then(|t| valley.trip(entrance, exit, t))
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    /// With `--minute=N`, the basin at minute N.
+    fn show(valley: &Self::Input, params: &Params) -> Result<Option<String>> {
+        if !params.flag("minute") {
+            return Ok(None);
+        }
+        let t = params.get("minute", 0)?;
+        Ok(Some(format!("Minute {t}:\n{}\n", valley.draw(t))))
+    }
+}
@@ -0,0 +1,80 @@
+#![warn(clippy::pedantic)]
+
+use crate::solution::{end_error, parse_lines, Answer, Error, Result, Solution};
+use std::collections::HashSet;
+
+/// An item in all three rucksacks of a group.
+fn badge(group: &[String]) -> Option<char> {
+    let [first, second, third] = group else {
+        return None;
+    };
+    first
+        .chars()
+        .find(|c| second.contains(*c) && third.contains(*c))
+}
+
+fn priority(c: char) -> u32 {
+    if c.is_lowercase() {
+        (c as u32) - ('a' as u32) + 1
+    } else {
+        (c as u32) - ('A' as u32) + 27
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = parse_lines(input, "a rucksack", |line| {
+            line.chars()
+                .all(|c| c.is_ascii_alphabetic())
+                .then(|| line.to_string())
+        })?;
+        // Elves come in groups of three, sharing a badge.
+        for (n, group) in lines.chunks(3).enumerate() {
+            if group.len() < 3 {
+                return Err(end_error(input, "a group of three rucksacks"));
+            }
+            if badge(group).is_none() {
+                let n = 3 * n + 2;
+                return Err(Error::parse(
+                    n + 1,
+                    &lines[n],
+                    "a rucksack sharing an item with the two before",
+                ));
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        let mut total = 0;
+        for line in lines {
+            let line: Vec<char> = line.chars().collect();
+            let left: HashSet<char> = line[0..line.len() / 2]
+                .iter()
+                .copied()
+                .collect::<HashSet<_>>();
+            let right: HashSet<char> = line[line.len() / 2..]
+                .iter()
+                .copied()
+                .collect::<HashSet<_>>();
+            let diff = left.intersection(&right);
+            for c in diff {
+                total += priority(*c);
+            }
+        }
+        total.into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        lines
+            .chunks(3)
+            .filter_map(badge)
+            .map(priority)
+            .sum::<u32>()
+            .into()
+    }
+}
@@ -0,0 +1,32 @@
+use crate::solution::*;
+use sscanf::sscanf;
+
+type InputLine = (u32, u32,u32, u32);
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<InputLine>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, "`a-b,c-d`", |line| {
+            sscanf!(line, "{u32}-{u32},{u32}-{u32}").ok()
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|(a1, a2, b1, b2)| (a1 >= b1 && a2 <= b2) || (a1 <= b1 && a2 >= b2))
+            .count()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|(a1, a2, b1, b2)| !((a1 < b1 && a2 < b1) || (a1 > b1 && a1 > b2)))
+            .count()
+            .into()
+    }
+}
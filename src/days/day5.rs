@@ -0,0 +1,93 @@
+use crate::solution::*;
+use sscanf::sscanf;
+
+pub struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+type Stack = Vec<char>;
+type Stacks = Vec<Stack>;
+type Instructions = Vec<Instruction>;
+
+fn read_input(s: &str) -> Result<(Stacks, Instructions)> {
+    // Each stack is three characters wide, plus a separator.
+    let width = (s.lines().next().unwrap_or_default().len() + 1) / 4;
+    let mut stacks: Stacks = vec![vec![]; width];
+    let mut instructions: Instructions = vec![];
+
+    let mut sizes: Vec<usize> = vec![];
+    let mut part1 = true;
+    for (n, line) in s.lines().enumerate() {
+        if line.is_empty() { // Finalize stacks, move on to part 2.
+            for stk in &mut stacks {
+                stk.reverse();
+            }
+            sizes = stacks.iter().map(Vec::len).collect();
+            part1 = false;
+        } else if part1 && line.trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+            // Stack numbers, under the crates.
+        } else if part1 { // Read stacks
+            let mut chars = line.chars();
+            for (i, item) in stacks.iter_mut().enumerate() {
+                let char = chars.nth(if i == 0 { 1 } else { 3 })
+                    .ok_or_else(|| Error::parse(n + 1, line, "a row of crates"))?;
+                if char != ' ' {
+                    item.push(char);
+                }
+            }
+        } else { // Read instructions
+            let (count, from, to) =
+                sscanf!(line, "move {usize} from {usize} to {usize}")
+                .map_err(|_| Error::parse(n + 1, line, "`move n from a to b`"))?;
+            if from == 0 || from > width || to == 0 || to > width {
+                return Err(Error::parse(n + 1, line, &format!("stacks between 1 and {width}")));
+            }
+            // Stacks only change size by moves, whatever the crane.
+            if sizes[from - 1] < count {
+                return Err(Error::parse(n + 1, line, &format!("at most {} crates", sizes[from - 1])));
+            }
+            sizes[from - 1] -= count;
+            sizes[to - 1] += count;
+            instructions.push(Instruction { count, from, to })
+        }
+    }
+    Ok((stacks, instructions))
+}
+
+fn tops(stacks: &Stacks) -> String {
+    stacks.iter().filter_map(|s| s.last()).collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Stacks, Instructions);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    fn part1((stacks, instructions): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+        for instr in instructions {
+            for _ in 0..instr.count {
+                let crt = stacks[instr.from - 1].pop().unwrap();
+                stacks[instr.to - 1].push(crt);
+            }
+        }
+        tops(&stacks).into()
+    }
+
+    fn part2((stacks, instructions): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+        for instr in instructions {
+            let from = instr.from - 1;
+            let idx = stacks[from].len() - instr.count;
+            let mut moved = stacks[from].drain(idx..).collect::<Vec<char>>();
+            stacks[instr.to-1].append(&mut moved);
+        }
+        tops(&stacks).into()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::solution::*;
+
+/// Find the position of the end of the first run of `len` distinct
+/// characters.
+fn find_marker(input: &[char], len: usize) -> Option<usize> {
+    let mut start = 0;
+    for idx in 0..input.len() {
+        if let Some(dupl) = input[start..idx]
+            .iter()
+            .position(|item| item == &input[idx]) {
+                start += dupl + 1;
+            }
+        if idx + 1 - start == len {
+            return Some(idx + 1);
+        }
+    }
+    None
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let line = input.lines().next().unwrap_or_default();
+        if line.is_empty() {
+            return Err(Error::parse(1, line, "a datastream"));
+        }
+        Ok(line.chars().collect())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        find_marker(input, 4).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        find_marker(input, 14).map_or(Answer::Unsolved, Answer::from)
+    }
+}
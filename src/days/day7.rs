@@ -0,0 +1,74 @@
+use crate::solution::*;
+use sscanf::sscanf;
+use std::collections::HashMap;
+
+pub struct Filesystem {
+    dirs: HashMap<String, u32>,
+    available: u32,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Filesystem;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut dirs: HashMap<String, u32> = HashMap::new();
+        let mut stack: Vec<String> = vec![];
+        let mut available: u32 = 70000000;
+        for (n, line) in input.lines().enumerate() {
+            if let Ok(cd) = sscanf!(line, "$ cd {str}") {
+                match cd {
+                    ".." => {
+                        stack.pop();
+                    }
+                    "/" => { /* noop */ }
+                    _ => {
+                        stack.push(cd.to_string());
+                    }
+                }
+                // } else if let Ok(dir) = sscanf!(&line, "dir {str}") {
+                // stack.push(dir.to_string());
+                // let repr = stack.join("/");
+                // dirs.entry(repr).or_insert(0);
+            } else if let Ok((size, _)) = sscanf!(line, "{u32} {str}") {
+                available = available
+                    .checked_sub(size)
+                    .ok_or_else(|| Error::parse(n + 1, line, "files that fit on the disk"))?;
+                for i in 0..=stack.len() {
+                    let key = stack[0..i].to_vec().join("/");
+                    let old_size = dirs.get(&key).unwrap_or(&0);
+                    dirs.insert(key, size + old_size);
+                }
+            } else if line != "$ ls" && !line.starts_with("dir ") {
+                return Err(Error::parse(n + 1, line, "a command or a directory listing"));
+            }
+        }
+        Ok(Filesystem { dirs, available })
+    }
+
+    /// Total size of dirs < 100000
+    fn part1(fs: &Self::Input) -> Answer {
+        fs.dirs
+            .values()
+            .filter(|size| **size <= 100000)
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Size of smallest dir that would free enough space, if some
+    /// space needs freeing.
+    fn part2(fs: &Self::Input) -> Answer {
+        let Some(need) = 30000000u32
+            .checked_sub(fs.available)
+            .filter(|need| *need > 0)
+        else {
+            return Answer::Unsolved;
+        };
+        fs.dirs
+            .values()
+            .filter(|size| **size >= need)
+            .min()
+            .map_or(Answer::Unsolved, |size| (*size).into())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::geom::*;
+use crate::matrix::*;
+use crate::solution::*;
+use std::collections::HashSet;
+
+/// Product of the number of trees seen from (x,y) in each direction.
+fn scenic_score(trees: &Matrix<i8>, x: isize, y: isize) -> u64 {
+    let max_height = trees[(x, y)];
+    Heading::ALL
+        .into_iter()
+        .map(|d| {
+            let mut count = 0;
+            for (_, tree) in trees.ray(Point2::new(x, y), d) {
+                count += 1;
+                if *tree >= max_height {
+                    break;
+                }
+            }
+            count
+        })
+        .product()
+}
+
+/// Trees visible from the left or the right of their row.
+fn visible_in_rows(trees: &Matrix<i8>) -> HashSet<Coord> {
+    let mut visible = HashSet::new();
+    for (y, row) in trees.rows().enumerate() {
+        let mut tallest = -1;
+        for (x, tree) in row.iter().enumerate() {
+            if *tree > tallest {
+                visible.insert(Point2::new(x as isize, y as isize));
+                tallest = *tree;
+            }
+        }
+        let mut tallest = -1;
+        for (x, tree) in row.iter().enumerate().rev() {
+            if *tree > tallest {
+                visible.insert(Point2::new(x as isize, y as isize));
+                tallest = *tree;
+            }
+        }
+    }
+    visible
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Matrix<i8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let trees = Matrix::from_str_with(input, |c| c.to_digit(10).map(|d| d as i8).ok_or(c))
+            .map_err(|(c, _)| grid_error(input, c.y, "a row of digits"))?;
+        if trees.vec.is_empty() {
+            return Err(Error::parse(1, "", "a row of digits"));
+        }
+        Ok(trees)
+    }
+
+    fn part1(trees: &Self::Input) -> Answer {
+        let mut visible = visible_in_rows(trees);
+        visible.extend(
+            visible_in_rows(&trees.transpose())
+                .into_iter()
+                .map(|p| Point2::new(p.y, p.x)),
+        );
+        visible.len().into()
+    }
+
+    fn part2(trees: &Self::Input) -> Answer {
+        let mut best = 0;
+        for x in 0..trees.width() as isize {
+            for y in 0..trees.height() as isize {
+                best = best.max(scenic_score(trees, x, y));
+            }
+        }
+        best.into()
+    }
+}
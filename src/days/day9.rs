@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use crate::geom::*;
+use crate::solution::*;
+
+type Command = (Heading, u32);
+
+fn read_command(s: &str) -> Option<Command> {
+    let (dir, dist) = s.split_once(' ')?;
+    let mut dir = dir.chars();
+    let heading = dir.next().and_then(Heading::from_char)?;
+    if dir.next().is_some() {
+        return None;
+    }
+    Some((heading, dist.parse::<u32>().ok()?))
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct CommandStream {
+    idx: usize,
+    rem: u32,
+    stream: Vec<Command>,
+}
+
+impl CommandStream {
+    fn new(cs: &[Command]) -> Self {
+        Self {
+            idx: 0,
+            rem: cs.first().map_or(0, |c| c.1),
+            stream: cs.to_vec(),
+        }
+    }
+}
+
+impl Iterator for CommandStream {
+    type Item = Heading;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rem > 0 {
+            self.rem -= 1;
+            Some(self.stream[self.idx].0)
+        } else if self.idx + 1 < self.stream.len() {
+            self.idx += 1;
+            self.rem = self.stream[self.idx].1;
+            self.next()
+        } else {
+            None
+        }
+    }
+}
+
+type Coord = Point2<i32>;
+
+struct Rope (Vec<Coord>);
+
+impl Rope {
+    fn new(knots: usize) -> Rope {
+        Rope(vec![Point2::new(0, 0); knots])
+    }
+
+    fn apply(&mut self, heading: Heading) {
+        self.0[0] = self.0[0].step(heading);
+
+        for k in 1..self.0.len() {
+            if self.0[k - 1].chebyshev(self.0[k]) <= 1 {
+                return;
+            }
+            let pull = (self.0[k - 1] - self.0[k]).signum();
+            self.0[k] += pull;
+        }
+    }
+}
+
+/// Count the positions visited by the tail of a rope of `knots` knots.
+fn tail_positions(commands: &[Command], knots: usize) -> usize {
+    let mut rope = Rope::new(knots);
+    let mut coords: HashSet<Coord> = HashSet::new();
+    for heading in CommandStream::new(commands) {
+        rope.apply(heading);
+        coords.insert(*rope.0.last().unwrap());
+    }
+    coords.len()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let commands = parse_lines(input, "a direction and a distance", read_command)?;
+        if commands.is_empty() {
+            return Err(end_error(input, "a direction and a distance"));
+        }
+        Ok(commands)
+    }
+
+    fn part1(commands: &Self::Input) -> Answer {
+        tail_positions(commands, 2).into()
+    }
+
+    fn part2(commands: &Self::Input) -> Answer {
+        tail_positions(commands, 10).into()
+    }
+}
@@ -0,0 +1,157 @@
+//! Solvers for each day of the calendar.
+//!
+//! Every module exposes a [`Solution`](crate::solution::Solution) for
+//! its puzzle.  [`DAYS`] lists them all, so that the `aoc` runner (and
+//! the individual `dayN` binaries) can dispatch to them.
+
+use std::process::ExitCode;
+
+use crate::input::{Params, Source};
+use crate::solution::{solve, Answer, Error, Result, Solved};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+
+/// A day's entry point: parses the puzzle input and solves both parts.
+pub type Solver = fn(&str, &Params) -> Result<Solved>;
+
+/// Every implemented day, in order.
+pub const DAYS: &[(u8, Solver)] = &[
+    (1, solve::<day1::Day1>),
+    (2, solve::<day2::Day2>),
+    (3, solve::<day3::Day3>),
+    (4, solve::<day4::Day4>),
+    (5, solve::<day5::Day5>),
+    (6, solve::<day6::Day6>),
+    (7, solve::<day7::Day7>),
+    (8, solve::<day8::Day8>),
+    (9, solve::<day9::Day9>),
+    (10, solve::<day10::Day10>),
+    (11, solve::<day11::Day11>),
+    (12, solve::<day12::Day12>),
+    (13, solve::<day13::Day13>),
+    (14, solve::<day14::Day14>),
+    (15, solve::<day15::Day15>),
+    (16, solve::<day16::Day16>),
+    (17, solve::<day17::Day17>),
+    (18, solve::<day18::Day18>),
+    (19, solve::<day19::Day19>),
+    (20, solve::<day20::Day20>),
+    (21, solve::<day21::Day21>),
+    (22, solve::<day22::Day22>),
+    (23, solve::<day23::Day23>),
+    (24, solve::<day24::Day24>),
+];
+
+/// Find the solver for a given day, if it's implemented.
+pub fn get(day: u8) -> Option<Solver> {
+    DAYS.iter().find(|(d, _)| *d == day).map(|(_, s)| *s)
+}
+
+/// How to run a day, as given on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    pub source: Source,
+    /// Report how long each step took.
+    pub time: bool,
+    /// Parameters for the day, on top of those of the source.
+    pub params: Params,
+}
+
+impl Options {
+    /// Read `--time`, leave the input and `--example` to
+    /// [`Source::from_args`], and take any other option as a
+    /// parameter.
+    pub fn from_args(day: u8, args: &[&str]) -> std::result::Result<Options, String> {
+        let (flags, rest): (Vec<&str>, Vec<&str>) = args
+            .iter()
+            .filter(|arg| **arg != "--time")
+            .partition(|arg| arg.starts_with("--") && **arg != "--example");
+        Ok(Options {
+            source: Source::from_args(day, &rest)?,
+            time: args.contains(&"--time"),
+            params: Params::from_args(flags)?,
+        })
+    }
+
+    /// The parameters of the source, overridden by those given on the
+    /// command line.
+    pub fn params(&self) -> Result<Params> {
+        let mut params = self.source.params()?;
+        params.extend(&self.params);
+        Ok(params)
+    }
+}
+
+/// Solve a day's puzzle from the given input and print the answers.
+pub fn run(day: u8, options: &Options) -> Result<()> {
+    print!("{}", report(day, options)?);
+    Ok(())
+}
+
+/// Solve a day's puzzle from the given input, and return what
+/// [`run`] prints.
+pub fn report(day: u8, options: &Options) -> Result<String> {
+    let solver = get(day).ok_or(Error::Unimplemented(day))?;
+    let source = &options.source;
+    let input = source.read()?;
+    let solved = solver(&input, &options.params()?).map_err(|e| e.in_file(&source.to_string()))?;
+    let mut report = solved.shown.unwrap_or_default();
+    report += &format!(
+        "{}\n{}\n",
+        format_answer(1, &solved.part1),
+        format_answer(2, &solved.part2)
+    );
+    if options.time {
+        report += &format!("Time: {}\n", solved.times);
+    }
+    Ok(report)
+}
+
+/// Format an answer the way the runner prints it.  Multi-line answers
+/// start on their own line.
+pub fn format_answer(part: u8, answer: &Answer) -> String {
+    match answer {
+        Answer::Text(text) if text.contains('\n') => format!("Part {part}:\n{text}"),
+        _ => format!("Part {part}: {answer}"),
+    }
+}
+
+/// Entry point of the `dayN` binaries.  They take an optional input
+/// path (`-` for standard input) or `--example`, `--time`, and the
+/// day's parameters.
+pub fn main(day: u8) -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result =
+        Options::from_args(day, &args).and_then(|options| run(day, &options).map_err(|e| e.to_string()));
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt::Display;
+use std::io;
+use std::path::PathBuf;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input file doesn't exist.
+    MissingFile(PathBuf),
+    /// Reading the input failed.  The first field says where.
+    Io(String, io::Error),
+    /// A line of the input doesn't look like it should.  Line numbers
+    /// start at 1.
+    Parse {
+        file: Option<String>,
+        line: usize,
+        text: String,
+        expected: String,
+    },
+    /// A day-specific parameter is unknown or has a bad value.
+    Param(String),
+    /// There's no solver for that day.
+    Unimplemented(u8),
+}
+
+impl Error {
+    /// A parse error at line `line` (1-based), whose text is `text`.
+    pub fn parse(line: usize, text: &str, expected: &str) -> Error {
+        Error::Parse {
+            file: None,
+            line,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Attach the name of the input to a parse error.
+    pub fn in_file(self, name: &str) -> Error {
+        match self {
+            Error::Parse {
+                file: None,
+                line,
+                text,
+                expected,
+            } => Error::Parse {
+                file: Some(name.to_string()),
+                line,
+                text,
+                expected,
+            },
+            e => e,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingFile(path) => write!(f, "{}: no such file", path.display()),
+            Error::Io(place, e) => write!(f, "{place}: {e}"),
+            Error::Parse {
+                file,
+                line,
+                text,
+                expected,
+            } => {
+                if let Some(file) = file {
+                    write!(f, "{file}:{line}: expected {expected}, found `{text}`")
+                } else {
+                    write!(f, "line {line}: expected {expected}, found `{text}`")
+                }
+            }
+            Error::Param(msg) => msg.fmt(f),
+            Error::Unimplemented(day) => write!(f, "Day {day} is not implemented."),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,275 @@
+//! Points and directions on grids, in two and three dimensions.
+//!
+//! On a 2D grid, `y` grows downwards: [`Heading::Up`] is `(0, -1)`.
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The integer types points can be made of.
+pub trait Scalar:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! scalar {
+    ($($t:ty),*) => {
+        $(impl Scalar for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+scalar!(i8, i16, i32, i64, i128, isize);
+
+fn abs_diff<T: Scalar>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn signum<T: Scalar>(a: T) -> T {
+    match a.cmp(&T::ZERO) {
+        std::cmp::Ordering::Less => -T::ONE,
+        std::cmp::Ordering::Equal => T::ZERO,
+        std::cmp::Ordering::Greater => T::ONE,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Ord> Point2<T> {
+    /// The smallest of each coordinate.
+    pub fn min_each(self, other: Self) -> Self {
+        Point2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// The largest of each coordinate.
+    pub fn max_each(self, other: Self) -> Self {
+        Point2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl<T: Scalar> Point2<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// Each coordinate replaced by its sign: -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Point2::new(signum(self.x), signum(self.y))
+    }
+
+    /// The point one step away in `heading`.
+    pub fn step(self, heading: Heading) -> Self {
+        self + heading.offset()
+    }
+
+    /// The points above, right, below and left of this one.
+    pub fn neighbours4(self) -> [Self; 4] {
+        Heading::ALL.map(|h| self.step(h))
+    }
+
+    /// The eight points around this one, row by row.
+    pub fn neighbours8(self) -> [Self; 8] {
+        let (o, l) = (T::ZERO, T::ONE);
+        [
+            Point2::new(-l, -l),
+            Point2::new(o, -l),
+            Point2::new(l, -l),
+            Point2::new(-l, o),
+            Point2::new(l, o),
+            Point2::new(-l, l),
+            Point2::new(o, l),
+            Point2::new(l, l),
+        ]
+        .map(|d| self + d)
+    }
+}
+
+impl<T: Scalar> Point3<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+
+    /// The points sharing a face with this one.
+    pub fn neighbours6(self) -> [Self; 6] {
+        let (o, l) = (T::ZERO, T::ONE);
+        [
+            Point3::new(-l, o, o),
+            Point3::new(l, o, o),
+            Point3::new(o, -l, o),
+            Point3::new(o, l, o),
+            Point3::new(o, o, -l),
+            Point3::new(o, o, l),
+        ]
+        .map(|d| self + d)
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident, $($field:ident),*) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        /// Scaling.
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, k: T) -> Self {
+                $point { $($field: self.$field * k),* }
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point_ops!(Point2, x, y);
+point_ops!(Point3, x, y, z);
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// One of the four directions on a grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Heading {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Heading {
+    /// All headings, clockwise from `Up`.
+    pub const ALL: [Heading; 4] = [Heading::Up, Heading::Right, Heading::Down, Heading::Left];
+
+    /// `U`, `R`, `D` or `L`.
+    pub fn from_char(c: char) -> Option<Heading> {
+        match c {
+            'U' => Some(Heading::Up),
+            'R' => Some(Heading::Right),
+            'D' => Some(Heading::Down),
+            'L' => Some(Heading::Left),
+            _ => None,
+        }
+    }
+
+    /// The move of one step in this heading.
+    pub fn offset<T: Scalar>(self) -> Point2<T> {
+        let (o, l) = (T::ZERO, T::ONE);
+        match self {
+            Heading::Up => Point2::new(o, -l),
+            Heading::Right => Point2::new(l, o),
+            Heading::Down => Point2::new(o, l),
+            Heading::Left => Point2::new(-l, o),
+        }
+    }
+
+    pub fn turn_right(self) -> Heading {
+        Heading::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Heading {
+        Heading::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Heading {
+        Heading::ALL[(self as usize + 2) % 4]
+    }
+}
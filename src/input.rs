@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::{number_errors, try_read_lines};
+
+/// Where to read a puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// The conventional location of a day's puzzle input.
+    pub fn puzzle(day: u8) -> Source {
+        Source::File(PathBuf::from(format!("inputs/{day}.txt")))
+    }
+
+    /// The conventional location of the example from a day's puzzle
+    /// text.
+    pub fn example(day: u8) -> Source {
+        Source::File(PathBuf::from(format!("inputs/{day}-example.txt")))
+    }
+
+    /// Interpret a command-line argument: `-` is standard input,
+    /// anything else a path.
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Pick the input from command-line arguments: at most one path
+    /// (or `-`), or `--example`.  Defaults to the puzzle input.
+    pub fn from_args(day: u8, args: &[&str]) -> Result<Source, String> {
+        let mut ret = None;
+        for arg in args {
+            let source = match *arg {
+                "--example" => Source::example(day),
+                flag if flag.starts_with("--") => return Err(format!("Unknown option: {flag}")),
+                path => Source::from_arg(path),
+            };
+            if ret.replace(source).is_some() {
+                return Err("More than one input given.".to_string());
+            }
+        }
+        Ok(ret.unwrap_or_else(|| Source::puzzle(day)))
+    }
+
+    /// Parameters that go with the input.  For `inputs/N.txt`, they
+    /// are read from `inputs/N.params` if it exists.
+    pub fn params(&self) -> Result<Params> {
+        let Source::File(path) = self else {
+            return Ok(Params::default());
+        };
+        let path = path.with_extension("params");
+        if !path.exists() {
+            return Ok(Params::default());
+        }
+        let text = std::fs::read_to_string(&path).map_err(|e| Error::Io(path.display().to_string(), e))?;
+        Params::from_args(text.split_whitespace())
+            .map_err(|e| Error::Param(format!("{}: {e}", path.display())))
+    }
+
+    /// Whether the input can be read.  Standard input is always
+    /// assumed to be there.
+    pub fn exists(&self) -> bool {
+        match self {
+            Source::File(path) => path.exists(),
+            Source::Stdin => true,
+        }
+    }
+
+    /// Read the whole input.
+    pub fn read(&self) -> Result<String> {
+        let lines: Vec<String> = match self {
+            Source::File(path) => try_read_lines(path)?.collect::<Result<_>>()?,
+            Source::Stdin => {
+                number_errors(self.to_string(), io::stdin().lock().lines()).collect::<Result<_>>()?
+            }
+        };
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::File(path) => path.display().fmt(f),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Day-specific parameters, given as `--name=value`, or just `--name`
+/// for a flag.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// Read parameters from arguments, which must all look like
+    /// `--name=value` or `--name`.
+    pub fn from_args<'a>(args: impl IntoIterator<Item = &'a str>) -> Result<Params, String> {
+        let mut params = Params::default();
+        for arg in args {
+            let param = arg
+                .strip_prefix("--")
+                .filter(|param| !param.is_empty())
+                .ok_or(format!("Not a parameter: {arg}"))?;
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+            params.0.insert(name.to_string(), value.to_string());
+        }
+        Ok(params)
+    }
+
+    /// Add the parameters of `other`, replacing those with the same
+    /// name.
+    pub fn extend(&mut self, other: &Params) {
+        self.0.extend(other.0.clone());
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Whether the flag `--name` is given.
+    pub fn flag(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// The value of `--name`, or `default` if it isn't given.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T> {
+        match self.0.get(name) {
+            None => Ok(default),
+            Some(value) => value
+                .parse()
+                .map_err(|_| Error::Param(format!("Bad value for --{name}: `{value}`"))),
+        }
+    }
+}
+
+/// Parse every line of the input with `f`, failing on the first line
+/// it rejects.
+pub fn parse_lines<T, F>(input: &str, expected: &str, mut f: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Option<T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(n, line)| f(line).ok_or_else(|| Error::parse(n + 1, line, expected)))
+        .collect()
+}
+
+/// An error about the end of the input, when it's missing something.
+pub fn end_error(input: &str, expected: &str) -> Error {
+    Error::parse(input.lines().count() + 1, "", expected)
+}
+
+/// An error about row `y` of a grid read with
+/// [`Matrix::from_str_with`](crate::matrix::Matrix::from_str_with).
+pub fn grid_error(input: &str, y: isize, expected: &str) -> Error {
+    let line = input.lines().nth(y as usize).unwrap_or_default();
+    Error::parse(y as usize + 1, line, expected)
+}
+
+/// A cursor over the lines of an input, for parsers that read more than
+/// one line at a time.  It remembers the current line number to report
+/// errors.
+pub struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+    line: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Lines {
+            lines: input.lines(),
+            line: 0,
+        }
+    }
+
+    /// Parse the next line with `f`.  Running out of lines is an error.
+    pub fn parse<T, F>(&mut self, expected: &str, f: F) -> Result<T>
+    where
+        F: FnOnce(&'a str) -> Option<T>,
+    {
+        let line = self.next().unwrap_or_default();
+        f(line).ok_or_else(|| Error::parse(self.line, line, expected))
+    }
+
+    /// An error about the line that was read last.
+    pub fn error(&self, text: &str, expected: &str) -> Error {
+        Error::parse(self.line, text, expected)
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line += 1;
+        self.lines.next()
+    }
+}
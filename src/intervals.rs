@@ -0,0 +1,189 @@
+//! Sets of integers stored as sorted, disjoint ranges.
+
+use std::ops::Range;
+
+/// A set of integers, as a sorted list of disjoint, non-adjacent
+/// half-open ranges.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet { ranges: vec![] }
+    }
+
+    /// The ranges of the set, in order.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> i64 {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        let idx = self.ranges.partition_point(|r| r.end <= n);
+        self.ranges.get(idx).is_some_and(|r| r.start <= n)
+    }
+
+    /// Add a range to the set, merging it with the ranges it overlaps
+    /// or touches.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        // Ranges strictly before and strictly after the new one.
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut ret = self.clone();
+        for r in &other.ranges {
+            ret.insert(r.clone());
+        }
+        ret
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ret = RangeSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ret.ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        ret
+    }
+
+    /// The integers of this set that aren't in `other`.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut ret = RangeSet::new();
+        let mut j = 0;
+        for r in &self.ranges {
+            let mut start = r.start;
+            // Skip the ranges of other that end before this one.
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < r.end {
+                if other.ranges[k].start > start {
+                    ret.ranges.push(start..other.ranges[k].start);
+                }
+                start = start.max(other.ranges[k].end);
+                k += 1;
+            }
+            if start < r.end {
+                ret.ranges.push(start..r.end);
+            }
+        }
+        ret
+    }
+
+    /// The ranges of `within` that aren't in the set.
+    pub fn gaps(&self, within: Range<i64>) -> impl Iterator<Item = Range<i64>> {
+        RangeSet::from(within).difference(self).ranges.into_iter()
+    }
+}
+
+impl From<Range<i64>> for RangeSet {
+    fn from(range: Range<i64>) -> Self {
+        let mut ret = RangeSet::new();
+        ret.insert(range);
+        ret
+    }
+}
+
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut ret = RangeSet::new();
+        for range in iter {
+            ret.insert(range);
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> RangeSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert() {
+        // Disjoint, touching and overlapping.
+        assert_eq!(set(&[5..7, 0..2]).ranges(), &[0..2, 5..7]);
+        assert_eq!(set(&[0..2, 2..4]), RangeSet::from(0..4));
+        assert_eq!(set(&[0..3, 2..5, 8..9, 4..8]), RangeSet::from(0..9));
+        assert_eq!(set(&[0..10, 3..4]), RangeSet::from(0..10));
+        assert_eq!(RangeSet::from(3..3), RangeSet::new());
+        assert_eq!(set(&[0..2, 5..7]).len(), 4);
+    }
+
+    #[test]
+    fn contains() {
+        let s = set(&[0..2, 5..7]);
+        assert!(s.contains(0) && s.contains(1) && s.contains(6));
+        assert!(!s.contains(-1) && !s.contains(2) && !s.contains(7));
+    }
+
+    #[test]
+    fn union() {
+        let a = set(&[0..2, 5..7]);
+        assert_eq!(a.union(&RangeSet::from(10..12)).ranges(), &[0..2, 5..7, 10..12]);
+        assert_eq!(a.union(&RangeSet::from(2..5)), RangeSet::from(0..7));
+        assert_eq!(a.union(&RangeSet::from(1..6)), RangeSet::from(0..7));
+        assert_eq!(a.union(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn intersection() {
+        let a = set(&[0..2, 5..7]);
+        assert_eq!(a.intersection(&RangeSet::from(10..12)), RangeSet::new());
+        assert_eq!(a.intersection(&RangeSet::from(2..5)), RangeSet::new());
+        assert_eq!(a.intersection(&RangeSet::from(1..6)).ranges(), &[1..2, 5..6]);
+        assert_eq!(a.intersection(&RangeSet::from(-5..20)), a);
+    }
+
+    #[test]
+    fn difference() {
+        let a = set(&[0..2, 5..7]);
+        assert_eq!(a.difference(&RangeSet::from(10..12)), a);
+        assert_eq!(a.difference(&RangeSet::from(2..5)), a);
+        assert_eq!(a.difference(&RangeSet::from(1..6)).ranges(), &[0..1, 6..7]);
+        assert_eq!(RangeSet::from(0..10).difference(&set(&[2..3, 5..6])).ranges(), &[0..2, 3..5, 6..10]);
+        assert_eq!(a.difference(&RangeSet::from(-5..20)), RangeSet::new());
+    }
+
+    #[test]
+    fn gaps() {
+        let a = set(&[0..2, 5..7]);
+        assert_eq!(a.gaps(-1..10).collect::<Vec<_>>(), vec![-1..0, 2..5, 7..10]);
+        assert_eq!(a.gaps(0..2).count(), 0);
+    }
+}
@@ -4,7 +4,21 @@ use std::io::BufRead;
 use std::path::Path;
 use std::ops::Sub;
 
+use error::Error;
+
+pub mod cycle;
+pub mod days;
+pub mod error;
+pub mod geom;
+pub mod input;
+pub mod intervals;
+pub mod num;
+pub mod parallel;
+pub mod solution;
 pub mod matrix;
+pub mod search;
+pub mod sparse_grid;
+pub mod treap;
 
 pub fn read_lines<P>(filename: P) -> io::Lines<io::BufReader<File>>
 where
@@ -14,6 +28,32 @@ where
     io::BufReader::new(file).lines()
 }
 
+/// Like [`read_lines`], but a missing file or a read error (eg, bad
+/// UTF-8) is reported instead of panicking.  Read errors carry the
+/// file name and line number.
+pub fn try_read_lines<P>(filename: P) -> error::Result<impl Iterator<Item = error::Result<String>>>
+where
+    P: AsRef<Path>,
+{
+    let path = filename.as_ref();
+    let file = File::open(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Error::MissingFile(path.to_path_buf()),
+        _ => Error::Io(path.display().to_string(), e),
+    })?;
+    Ok(number_errors(path.display().to_string(), io::BufReader::new(file).lines()))
+}
+
+/// Turn I/O errors from a line iterator into [`Error`]s pointing at the
+/// offending line.
+pub(crate) fn number_errors<B: BufRead>(
+    name: String,
+    lines: io::Lines<B>,
+) -> impl Iterator<Item = error::Result<String>> {
+    lines
+        .enumerate()
+        .map(move |(n, line)| line.map_err(|e| Error::Io(format!("{name}:{}", n + 1), e)))
+}
+
 pub fn bools_to_bin_string(n: &[bool]) -> String {
     n.iter().map(|x| if *x { '1' } else { '0' }).collect()
 }
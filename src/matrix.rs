@@ -3,6 +3,11 @@ use std::{
     ops::{Index, IndexMut},
 };
 
+pub use crate::geom::Heading;
+use crate::geom::Point2;
+
+pub type Coord = Point2<isize>;
+
 #[derive(Clone)]
 pub struct Matrix<T> {
     pub vec: Vec<T>,
@@ -11,7 +16,7 @@ pub struct Matrix<T> {
 }
 
 impl<T> Matrix<T> {
-    pub fn to_index(&self, (x, y): (isize, isize)) -> usize {
+    pub fn to_index(&self, Point2 { x, y }: Coord) -> usize {
         if !self.test_coords(x, y) {
             panic!("Bad coords: {x},{y}");
         }
@@ -30,13 +35,105 @@ impl<T> Matrix<T> {
         self.height
     }
 
-    pub fn to_coords(&self, idx: usize) -> (isize, isize) {
-        if !idx < self.vec.len() {
+    pub fn to_coords(&self, idx: usize) -> Coord {
+        if idx >= self.vec.len() {
             panic!("Out of bounds");
         }
         let x = (idx % self.width) as isize;
         let y = (idx / self.width) as isize;
-        (x, y)
+        Point2::new(x, y)
+    }
+
+    /// Read a grid, one row per line, converting each character with
+    /// `f`.  Short lines are padded with spaces, which go through `f`
+    /// like any other character.  On failure, returns the coordinates
+    /// of the offending character with the error.
+    pub fn from_str_with<E>(
+        s: &str,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Matrix<T>, (Coord, E)> {
+        let chars = Matrix::from_chars(s, ' ');
+        let vec = chars
+            .vec
+            .iter()
+            .enumerate()
+            .map(|(i, c)| f(*c).map_err(|e| (chars.to_coords(i), e)))
+            .collect::<Result<_, _>>()?;
+        Ok(Matrix {
+            vec,
+            width: chars.width,
+            height: chars.height,
+        })
+    }
+
+    /// The rows, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.vec.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: isize) -> &[T] {
+        if !self.test_coords(0, y) {
+            panic!("Bad row: {y}");
+        }
+        let start = y as usize * self.width;
+        &self.vec[start..start + self.width]
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: isize) -> impl Iterator<Item = &T> {
+        if !self.test_coords(x, 0) {
+            panic!("Bad column: {x}");
+        }
+        self.vec.iter().skip(x as usize).step_by(self.width)
+    }
+
+    /// Every cell with its coordinates, row by row.
+    pub fn iter_coords(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.vec
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.to_coords(i), cell))
+    }
+
+    /// Build a matrix from a function of the coordinates.
+    fn from_fn(width: usize, height: usize, f: impl Fn(Coord) -> T) -> Matrix<T> {
+        let vec = (0..width * height)
+            .map(|i| f(Point2::new((i % width) as isize, (i / width) as isize)))
+            .collect();
+        Matrix { vec, width, height }
+    }
+
+    /// The cell at `coords`, if it's inside the matrix.
+    pub fn cell(&self, coords: Coord) -> Option<&T> {
+        if self.test_coords(coords.x, coords.y) {
+            Some(&self[coords])
+        } else {
+            None
+        }
+    }
+
+    /// The cells above, right, below and left of `coords`, if they
+    /// exist.
+    pub fn neighbours4(&self, coords: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        coords
+            .neighbours4()
+            .into_iter()
+            .filter_map(|next| self.cell(next).map(|cell| (next, cell)))
+    }
+
+    /// Like [`Matrix::neighbours4`], with the diagonals.
+    pub fn neighbours8(&self, coords: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        coords
+            .neighbours8()
+            .into_iter()
+            .filter_map(|next| self.cell(next).map(|cell| (next, cell)))
+    }
+
+    /// The cells from `coords` (excluded) to the edge of the matrix,
+    /// going in `heading`.
+    pub fn ray(&self, coords: Coord, heading: Heading) -> impl Iterator<Item = (Coord, &T)> {
+        std::iter::successors(Some(coords.step(heading)), move |c| Some(c.step(heading)))
+            .map_while(|c| self.cell(c).map(|cell| (c, cell)))
     }
 
     pub fn test_coords(&self, x: isize, y: isize) -> bool {
@@ -111,30 +208,66 @@ impl<T: Copy> Matrix<T> {
     }
 }
 
+impl<T: Clone> Matrix<T> {
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Matrix<T> {
+        Matrix::from_fn(self.height, self.width, |Point2 { x, y }| self[(y, x)].clone())
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Matrix<T> {
+        let last = self.height as isize - 1;
+        Matrix::from_fn(self.height, self.width, |Point2 { x, y }| self[(y, last - x)].clone())
+    }
+
+    /// Mirror left to right.
+    pub fn flip_h(&self) -> Matrix<T> {
+        let last = self.width as isize - 1;
+        Matrix::from_fn(self.width, self.height, |Point2 { x, y }| self[(last - x, y)].clone())
+    }
+}
+
+impl Matrix<char> {
+    /// Read a grid of characters, one row per line.  Lines shorter than
+    /// the longest one are padded with `fill`.
+    pub fn from_chars(s: &str, fill: char) -> Matrix<char> {
+        let width = s.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let height = s.lines().count();
+        let mut vec = Vec::with_capacity(width * height);
+        for line in s.lines() {
+            let end = vec.len() + width;
+            vec.extend(line.chars());
+            vec.resize(end, fill);
+        }
+        Matrix { vec, width, height }
+    }
+}
+
 impl<T: Copy + Default> Matrix<T> {
     pub fn new(width: usize, height: usize) -> Self {
         Self::new_default(width, height, T::default())
     }
 }
 
-impl<T> Index<(isize, isize)> for Matrix<T> {
+/// Matrices can be indexed by [`Coord`]s or by `(x, y)` tuples.
+impl<T, C: Into<Coord>> Index<C> for Matrix<T> {
     type Output = T;
 
-    fn index(&self, coords: (isize, isize)) -> &Self::Output {
-        &self.vec[self.to_index(coords)]
+    fn index(&self, coords: C) -> &Self::Output {
+        &self.vec[self.to_index(coords.into())]
     }
 }
 
-impl<T> IndexMut<(isize, isize)> for Matrix<T> {
-    fn index_mut(&mut self, coords: (isize, isize)) -> &mut Self::Output {
-        let i = self.to_index(coords);
+impl<T, C: Into<Coord>> IndexMut<C> for Matrix<T> {
+    fn index_mut(&mut self, coords: C) -> &mut Self::Output {
+        let i = self.to_index(coords.into());
         &mut self.vec[i]
     }
 }
 
 impl<T: Display> Display for Matrix<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        for i in 0..self.vec.capacity() {
+        for i in 0..self.vec.len() {
             if i % self.width == 0 {
                 writeln!(f)?
             }
@@ -146,7 +279,7 @@ impl<T: Display> Display for Matrix<T> {
 
 impl Matrix<u8> {
     pub fn draw09(&self) {
-        for i in 0..self.vec.capacity() {
+        for i in 0..self.vec.len() {
             if i % self.width == 0 {
                 println!();
             }
@@ -163,3 +296,36 @@ impl Matrix<u8> {
         println!();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(m: &Matrix<char>) -> Vec<String> {
+        m.rows().map(|row| row.iter().collect()).collect()
+    }
+
+    #[test]
+    fn transformations() {
+        let m = Matrix::from_chars("abc\ndef", ' ');
+        assert_eq!(rows(&m.rotate_cw()), ["da", "eb", "fc"]);
+        assert_eq!(rows(&m.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(rows(&m.flip_h()), ["cba", "fed"]);
+        let back = m.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(rows(&back), rows(&m));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let m = Matrix::from_chars("abc\ndef", ' ');
+        assert_eq!(m.row(1), ['d', 'e', 'f']);
+        assert_eq!(m.column(0).collect::<String>(), "ad");
+        assert_eq!(m.column(2).collect::<String>(), "cf");
+    }
+
+    #[test]
+    #[should_panic(expected = "Bad column")]
+    fn column_out_of_bounds() {
+        Matrix::from_chars("abc\ndef", ' ').column(3).count();
+    }
+}
@@ -0,0 +1,327 @@
+//! Number theory helpers, and exact fractions.
+
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The integer types [`gcd`] and [`lcm`] work on.
+pub trait Integer: Copy + Eq + Ord + Div<Output = Self> {
+    const ZERO: Self;
+
+    /// Greatest common divisor, or `None` if it doesn't fit in the
+    /// type (`gcd(i128::MIN, 0)` is 2¹²⁷).
+    fn checked_gcd(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// The absolute value, or `None` if it doesn't fit.
+    fn checked_magnitude(self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    (signed: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+
+            fn checked_gcd(self, other: Self) -> Option<Self> {
+                // Unsigned, as the magnitude of MIN doesn't fit.
+                let (mut a, mut b) = (self.unsigned_abs(), other.unsigned_abs());
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                Self::try_from(a).ok()
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_magnitude(self) -> Option<Self> {
+                self.checked_abs()
+            }
+        })*
+    };
+    (unsigned: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+
+            fn checked_gcd(self, other: Self) -> Option<Self> {
+                let (mut a, mut b) = (self, other);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                Some(a)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_magnitude(self) -> Option<Self> {
+                Some(self)
+            }
+        })*
+    };
+}
+
+integer!(signed: i8, i16, i32, i64, i128, isize);
+integer!(unsigned: u8, u16, u32, u64, u128, usize);
+
+/// Greatest common divisor, never negative.  `gcd(0, 0)` is 0.  Panics
+/// if it doesn't fit in the type.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.checked_gcd(b).expect("gcd overflow")
+}
+
+/// Least common multiple, never negative.  It's 0 if either number is.
+/// Panics if it doesn't fit in the type.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("lcm overflow")
+}
+
+/// Like [`lcm`], but `None` if it doesn't fit.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        Some(T::ZERO)
+    } else {
+        (a / a.checked_gcd(b)?).checked_mul(b)?.checked_magnitude()
+    }
+}
+
+/// The inverse of `a` modulo `m`, in `0..m`, if `m` is positive and
+/// `a` and `m` are coprime.
+pub fn modinv(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+    // Extended Euclid: keep old_s * a ≡ old_r (mod m).
+    let (mut old_r, mut r) = (a.rem_euclid(m), m);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    (old_r == 1).then(|| old_s.rem_euclid(m))
+}
+
+/// A fraction in lowest terms, with a positive denominator.
+///
+/// The operators panic on overflow and division by zero, like the
+/// integer ones do in debug builds; the `checked_` methods return
+/// `None` instead.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// `num / den`.  Panics if `den` is 0, or if the fraction doesn't
+    /// fit.
+    pub fn new(num: i128, den: i128) -> Self {
+        Self::checked_new(num, den).expect("Invalid rational")
+    }
+
+    /// `num / den`, or `None` if `den` is 0, or if the fraction in
+    /// lowest terms doesn't fit.
+    pub fn checked_new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let g = num.checked_gcd(den)?;
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Some(Rational {
+                num: num.checked_neg()?,
+                den: den.checked_neg()?,
+            })
+        } else {
+            Some(Rational { num, den })
+        }
+    }
+
+    pub fn numer(self) -> i128 {
+        self.num
+    }
+
+    pub fn denom(self) -> i128 {
+        self.den
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.den == 1
+    }
+
+    /// The value, if it's a whole number.
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.num)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let den = checked_lcm(self.den, other.den)?;
+        let a = self.num.checked_mul(den / self.den)?;
+        let b = other.num.checked_mul(den / other.den)?;
+        Self::checked_new(a.checked_add(b)?, den)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // Simplify across first, to keep the products small.
+        let g1 = self.num.checked_gcd(other.den)?;
+        let g2 = other.num.checked_gcd(self.den)?;
+        Self::checked_new(
+            (self.num / g1).checked_mul(other.num / g2)?,
+            (self.den / g2).checked_mul(other.den / g1)?,
+        )
+    }
+
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(other.checked_recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Rational {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    /// `1 / self`, or `None` for 0.
+    pub fn checked_recip(self) -> Option<Self> {
+        Self::checked_new(self.den, self.num)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Rational { num: n, den: 1 }
+    }
+}
+
+macro_rules! rational_op {
+    ($trait:ident, $method:ident, $checked:ident, $what:literal) => {
+        impl $trait for Rational {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                self.$checked(other).expect($what)
+            }
+        }
+    };
+}
+
+rational_op!(Add, add, checked_add, "Rational addition overflow");
+rational_op!(Sub, sub, checked_sub, "Rational subtraction overflow");
+rational_op!(Mul, mul, checked_mul, "Rational multiplication overflow");
+rational_op!(Div, div, checked_div, "Rational division by zero or overflow");
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("Rational negation overflow")
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den == 1 {
+            self.num.fmt(f)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, -5), 5);
+        assert_eq!(gcd(0u64, 0), 0);
+        assert_eq!(lcm(4, -6), 12);
+        assert_eq!(lcm(0, 7), 0);
+        assert_eq!([2u64, 3, 4, 6].into_iter().fold(1, lcm), 12);
+    }
+
+    #[test]
+    fn gcd_and_lcm_overflow() {
+        assert_eq!(i128::MIN.checked_gcd(6), Some(2));
+        assert_eq!(i128::MIN.checked_gcd(0), None);
+        assert_eq!(i128::MIN.checked_gcd(i128::MIN), None);
+        assert_eq!(checked_lcm(1i128 << 70, 3 << 60), Some(3 << 70));
+        assert_eq!(checked_lcm((1i128 << 70) + 1, (1 << 70) - 1), None);
+        assert_eq!(checked_lcm(i128::MIN, 1), None);
+        assert_eq!(checked_lcm(u8::MAX, 2), None);
+    }
+
+    #[test]
+    fn modular_inverse() {
+        assert_eq!(modinv(3, 7), Some(5));
+        assert_eq!(modinv(-3, 7), Some(2));
+        assert_eq!(modinv(10, 17).map(|inv| inv * 10 % 17), Some(1));
+        assert_eq!(modinv(4, 8), None);
+        assert_eq!(modinv(3, 1), Some(0));
+        assert_eq!(modinv(3, 0), None);
+        assert_eq!(modinv(3, -7), None);
+    }
+
+    #[test]
+    fn rationals() {
+        let half = Rational::new(2, 4);
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(Rational::new(3, -6), Rational::new(-1, 2));
+        assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(half - Rational::ONE, Rational::new(-1, 2));
+        assert_eq!(half * Rational::new(4, 3), Rational::new(2, 3));
+        assert_eq!(half / Rational::new(1, 4), Rational::from(2));
+        assert_eq!(-half, Rational::new(-1, 2));
+        assert_eq!((half + half).to_integer(), Some(1));
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(half.to_string(), "1/2");
+        assert_eq!(Rational::from(-3).to_string(), "-3");
+    }
+
+    #[test]
+    fn rationals_checked() {
+        assert_eq!(Rational::checked_new(1, 0), None);
+        assert_eq!(Rational::checked_new(i128::MIN, 1).map(Rational::numer), Some(i128::MIN));
+        assert_eq!(Rational::checked_new(i128::MIN, -1), None);
+        assert_eq!(Rational::checked_new(1, i128::MIN), None);
+        assert_eq!(Rational::checked_new(i128::MIN, 2), Some(Rational::from(i128::MIN / 2)));
+        assert_eq!(Rational::ZERO.checked_recip(), None);
+        assert_eq!(Rational::ONE.checked_div(Rational::ZERO), None);
+        assert_eq!(Rational::from(i128::MIN).checked_neg(), None);
+
+        let a = Rational::new(1, (1 << 70) + 1);
+        let b = Rational::new(1, (1 << 70) - 1);
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(a.checked_sub(b), None);
+        assert_eq!(a.checked_mul(b), None);
+        assert_eq!(Rational::from(i128::MAX).checked_add(Rational::ONE), None);
+        assert_eq!(
+            Rational::from(1 << 100).checked_mul(Rational::new(1, 1 << 90)),
+            Some(Rational::from(1 << 10))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn rational_operator_overflow() {
+        let _ = Rational::new(1, (1 << 70) + 1) + Rational::new(1, (1 << 70) - 1);
+    }
+}
@@ -0,0 +1,30 @@
+//! Running independent pieces of work at the same time.
+//!
+//! With the `parallel` feature, work is spread over rayon's thread
+//! pool.  Without it, everything runs in order on the current thread.
+//! Either way, results come back in the order of the inputs.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Apply `f` to every item, possibly concurrently.
+#[cfg(feature = "parallel")]
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    items.par_iter().map(f).collect()
+}
+
+/// Apply `f` to every item, possibly concurrently.
+#[cfg(not(feature = "parallel"))]
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    items.iter().map(f).collect()
+}
@@ -0,0 +1,130 @@
+//! Shortest paths over implicit graphs.
+//!
+//! A graph is given by a function returning the neighbours of a node
+//! (with the cost of the edge, for weighted searches).  The searches
+//! return [`Paths`]: the distance to every node reached, and its
+//! predecessor on a shortest path.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The result of a search.
+#[derive(Clone, Debug)]
+pub struct Paths<N, C> {
+    pub dist: HashMap<N, C>,
+    pub prev: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Paths {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+        }
+    }
+
+    /// Distance from the start to `node`, if it was reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    /// A shortest path from the start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, where every edge costs 1.  There can be
+/// several starting nodes.
+pub fn bfs<N, I>(starts: impl IntoIterator<Item = N>, neighbours: impl Fn(N) -> I) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        paths.dist.insert(start.clone(), 0);
+        queue.push_back(start);
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let dist = paths.dist[&node] + 1;
+        for next in neighbours(node.clone()) {
+            if let Entry::Vacant(e) = paths.dist.entry(next.clone()) {
+                e.insert(dist);
+                paths.prev.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm, from several starting nodes.  Costs must not
+/// be negative; `C::default()` is taken as zero.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl Fn(N) -> I,
+) -> Paths<N, C>
+where
+    N: Clone + Ord + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, |_| false, neighbours, |_| C::default()).0
+}
+
+/// A* search, stopping at the first node for which `goal` holds, which
+/// is returned with the paths.  `heuristic` must never overestimate
+/// the distance to a goal.  Without a goal, this explores the whole
+/// graph.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    goal: impl Fn(&N) -> bool,
+    neighbours: impl Fn(N) -> I,
+    heuristic: impl Fn(&N) -> C,
+) -> (Paths<N, C>, Option<N>)
+where
+    N: Clone + Ord + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        paths.dist.insert(start.clone(), C::default());
+        heap.push(Reverse((heuristic(&start), C::default(), start)));
+    }
+
+    while let Some(Reverse((_, dist, node))) = heap.pop() {
+        if paths.dist[&node] < dist {
+            // Already reached by a shorter path.
+            continue;
+        }
+        if goal(&node) {
+            return (paths, Some(node));
+        }
+        for (next, cost) in neighbours(node.clone()) {
+            let alt = dist + cost;
+            if paths.dist.get(&next).is_some_and(|d| *d <= alt) {
+                continue;
+            }
+            paths.dist.insert(next.clone(), alt);
+            paths.prev.insert(next.clone(), node.clone());
+            heap.push(Reverse((alt + heuristic(&next), alt, next)));
+        }
+    }
+    (paths, None)
+}
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+pub use crate::error::{Error, Result};
+pub use crate::input::{end_error, grid_error, parse_lines, Lines, Params};
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Unsolved,
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, i128, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => n.fmt(f),
+            Answer::Text(s) => s.fmt(f),
+            Answer::Unsolved => write!(f, "(unsolved)"),
+        }
+    }
+}
+
+/// A day's puzzle.  The input is parsed once, then shared by both
+/// parts.
+pub trait Solution {
+    type Input;
+
+    /// Names of the parameters the puzzle takes, as `--name=value`.
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parse the input, given parameters.  Only days that take
+    /// parameters need to implement this.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Extra output asked for with parameters, such as a drawing.  The
+    /// runner prints it before the answers, and it isn't timed.
+    fn show(_input: &Self::Input, _params: &Params) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// How long each step of a solution took.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Times {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Times {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+impl Display for Times {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parse {:.2?}, part 1 {:.2?}, part 2 {:.2?}, total {:.2?}",
+            self.parse,
+            self.part1,
+            self.part2,
+            self.total()
+        )
+    }
+}
+
+/// Both answers to a puzzle, and the time it took to find them.
+#[derive(Clone, Debug)]
+pub struct Solved {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub times: Times,
+    /// What [`Solution::show`] returned.
+    pub shown: Option<String>,
+}
+
+/// Parse the input and solve both parts, timing each step.
+pub fn solve<S: Solution>(input: &str, params: &Params) -> Result<Solved> {
+    if let Some(name) = params.names().find(|name| !S::PARAMS.contains(name)) {
+        return Err(Error::Param(format!("Unknown parameter: --{name}")));
+    }
+
+    let start = Instant::now();
+    let input = S::parse_with(input, params)?;
+    let parsed = Instant::now();
+    let part1 = S::part1(&input);
+    let solved1 = Instant::now();
+    let part2 = S::part2(&input);
+    let solved2 = Instant::now();
+
+    Ok(Solved {
+        part1,
+        part2,
+        times: Times {
+            parse: parsed - start,
+            part1: solved1 - parsed,
+            part2: solved2 - solved1,
+        },
+        shown: S::show(&input, params)?,
+    })
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use crate::geom::{Heading, Point2};
+use crate::matrix::{Coord, Matrix};
+
+/// A grid without fixed bounds, storing only the cells that are set.
+/// Coordinates can be negative.  The bounding box of the set cells is
+/// kept up to date.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    bounds: Option<(Coord, Coord)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest and largest coordinates of the set cells, both
+    /// included.
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        self.bounds
+    }
+
+    /// Width of the bounding box.
+    pub fn width(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    /// Height of the bounding box.
+    pub fn height(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    pub fn contains(&self, coords: Coord) -> bool {
+        self.cells.contains_key(&coords)
+    }
+
+    pub fn cell(&self, coords: Coord) -> Option<&T> {
+        self.cells.get(&coords)
+    }
+
+    pub fn cell_mut(&mut self, coords: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&coords)
+    }
+
+    /// Set a cell, returning its previous value.
+    pub fn insert(&mut self, coords: Coord, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (coords, coords),
+            Some((min, max)) => (min.min_each(coords), max.max_each(coords)),
+        });
+        self.cells.insert(coords, value)
+    }
+
+    /// Unset a cell, returning its value.
+    pub fn remove(&mut self, coords: Coord) -> Option<T> {
+        let ret = self.cells.remove(&coords)?;
+        if let Some((min, max)) = self.bounds {
+            if coords.x == min.x || coords.x == max.x || coords.y == min.y || coords.y == max.y {
+                self.bounds = self.compute_bounds();
+            }
+        }
+        Some(ret)
+    }
+
+    fn compute_bounds(&self) -> Option<(Coord, Coord)> {
+        let mut coords = self.cells.keys();
+        let first = *coords.next()?;
+        Some(coords.fold((first, first), |(min, max), c| (min.min_each(*c), max.max_each(*c))))
+    }
+
+    /// Every set cell with its coordinates, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(coords, cell)| (*coords, cell))
+    }
+
+    /// The set cells above, right, below and left of `coords`.
+    pub fn neighbours4(&self, coords: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        coords
+            .neighbours4()
+            .into_iter()
+            .filter_map(|next| self.cell(next).map(|cell| (next, cell)))
+    }
+
+    /// Like [`SparseGrid::neighbours4`], with the diagonals.
+    pub fn neighbours8(&self, coords: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        coords
+            .neighbours8()
+            .into_iter()
+            .filter_map(|next| self.cell(next).map(|cell| (next, cell)))
+    }
+
+    /// The set cells from `coords` (excluded) to the edge of the
+    /// bounding box, going in `heading`.
+    pub fn ray(&self, coords: Coord, heading: Heading) -> impl Iterator<Item = (Coord, &T)> {
+        let (min, max) = self.bounds.unwrap_or((Point2::new(0, 0), Point2::new(-1, -1)));
+        std::iter::successors(Some(coords.step(heading)), move |c| Some(c.step(heading)))
+            .take_while(move |c| (min.x..=max.x).contains(&c.x) && (min.y..=max.y).contains(&c.y))
+            .filter_map(|c| self.cell(c).map(|cell| (c, cell)))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy> SparseGrid<T> {
+    pub fn get(&self, x: isize, y: isize) -> Option<T> {
+        self.cell(Point2::new(x, y)).copied()
+    }
+
+    pub fn get_or(&self, x: isize, y: isize, default: T) -> T {
+        self.get(x, y).unwrap_or(default)
+    }
+
+    /// The bounding box as a dense matrix, with `fill` for the unset
+    /// cells.  Also returns the coordinates of the top left corner,
+    /// which becomes `(0, 0)` in the matrix.
+    pub fn to_matrix(&self, fill: T) -> (Matrix<T>, Coord) {
+        let origin = self.bounds.map_or(Point2::new(0, 0), |(min, _)| min);
+        let mut matrix = Matrix::new_default(self.width(), self.height(), fill);
+        for (coords, cell) in self.iter() {
+            matrix[coords - origin] = *cell;
+        }
+        (matrix, origin)
+    }
+
+    /// The cells of a matrix for which `keep` holds.
+    pub fn from_matrix(matrix: &Matrix<T>, keep: impl Fn(&T) -> bool) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (coords, cell) in matrix.iter_coords().filter(|(_, cell)| keep(cell)) {
+            grid.insert(coords, *cell);
+        }
+        grid
+    }
+}
+
+impl<T, C: Into<Coord>> Index<C> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, coords: C) -> &Self::Output {
+        let coords = coords.into();
+        self.cell(coords)
+            .unwrap_or_else(|| panic!("Unset cell: {coords}"))
+    }
+}
+
+impl<T, C: Into<Coord>> IndexMut<C> for SparseGrid<T> {
+    fn index_mut(&mut self, coords: C) -> &mut Self::Output {
+        let coords = coords.into();
+        self.cell_mut(coords)
+            .unwrap_or_else(|| panic!("Unset cell: {coords}"))
+    }
+}
@@ -0,0 +1,219 @@
+//! A list with fast positional edits, as an implicit treap.
+//!
+//! Elements are identified by the id [`IndexedList::push`] returns, and
+//! keep it when they move around: removing, inserting and finding the
+//! position of an element all take logarithmic time.
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    priority: u64,
+    left: usize,
+    right: usize,
+    parent: usize,
+    // Number of elements in the subtree.
+    size: usize,
+}
+
+/// A sequence of values, balanced as a treap keyed on the position.
+/// Values are never dropped before the list: a removed element can be
+/// inserted back with its id.
+#[derive(Clone, Debug)]
+pub struct IndexedList<T> {
+    nodes: Vec<Node<T>>,
+    root: usize,
+    // State of the xorshift generator for priorities.  It's seeded the
+    // same every time, so that runs are reproducible.
+    seed: u64,
+}
+
+impl<T> IndexedList<T> {
+    pub fn new() -> Self {
+        IndexedList {
+            nodes: vec![],
+            root: NIL,
+            seed: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    /// Number of elements in the list, removed ones excluded.
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root == NIL
+    }
+
+    /// Append a value, and return its id.
+    pub fn push(&mut self, value: T) -> usize {
+        let id = self.nodes.len();
+        let priority = self.next_priority();
+        self.nodes.push(Node {
+            value,
+            priority,
+            left: NIL,
+            right: NIL,
+            parent: NIL,
+            size: 1,
+        });
+        let root = self.merge(self.root, id);
+        self.set_root(root);
+        id
+    }
+
+    /// The value of an element, even if it was removed.
+    pub fn get(&self, id: usize) -> &T {
+        &self.nodes[id].value
+    }
+
+    /// The id of the element at `index`.
+    pub fn at(&self, mut index: usize) -> usize {
+        assert!(index < self.len(), "Index out of bounds: {index}");
+        let mut node = self.root;
+        loop {
+            let left = self.size(self.nodes[node].left);
+            match index.cmp(&left) {
+                std::cmp::Ordering::Less => node = self.nodes[node].left,
+                std::cmp::Ordering::Equal => return node,
+                std::cmp::Ordering::Greater => {
+                    index -= left + 1;
+                    node = self.nodes[node].right;
+                }
+            }
+        }
+    }
+
+    /// The position of an element in the list.  It must not be removed.
+    pub fn index_of(&self, id: usize) -> usize {
+        let mut index = self.size(self.nodes[id].left);
+        let mut node = id;
+        while self.nodes[node].parent != NIL {
+            let parent = self.nodes[node].parent;
+            if self.nodes[parent].right == node {
+                index += self.size(self.nodes[parent].left) + 1;
+            }
+            node = parent;
+        }
+        assert!(node == self.root, "Element {id} isn't in the list");
+        index
+    }
+
+    /// Take out the element at `index`, and return its id.
+    pub fn remove(&mut self, index: usize) -> usize {
+        assert!(index < self.len(), "Index out of bounds: {index}");
+        let (before, rest) = self.split(self.root, index);
+        let (id, after) = self.split(rest, 1);
+        let root = self.merge(before, after);
+        self.set_root(root);
+        self.nodes[id].parent = NIL;
+        id
+    }
+
+    /// Put a removed element back, at `index`.
+    pub fn insert(&mut self, index: usize, id: usize) {
+        assert!(index <= self.len(), "Index out of bounds: {index}");
+        let (before, after) = self.split(self.root, index);
+        let left = self.merge(before, id);
+        let root = self.merge(left, after);
+        self.set_root(root);
+    }
+
+    /// The values, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len()).map(|index| self.get(self.at(index)))
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+
+    fn size(&self, node: usize) -> usize {
+        if node == NIL {
+            0
+        } else {
+            self.nodes[node].size
+        }
+    }
+
+    fn set_root(&mut self, node: usize) {
+        self.root = node;
+        if node != NIL {
+            self.nodes[node].parent = NIL;
+        }
+    }
+
+    /// Fix the size of a node and the parent of its children after they
+    /// changed.
+    fn update(&mut self, node: usize) {
+        let Node { left, right, .. } = self.nodes[node];
+        self.nodes[node].size = 1 + self.size(left) + self.size(right);
+        for child in [left, right] {
+            if child != NIL {
+                self.nodes[child].parent = node;
+            }
+        }
+    }
+
+    /// Split a subtree into its first `count` elements and the rest.
+    fn split(&mut self, node: usize, count: usize) -> (usize, usize) {
+        if node == NIL {
+            return (NIL, NIL);
+        }
+        let left = self.nodes[node].left;
+        if count <= self.size(left) {
+            let (a, b) = self.split(left, count);
+            self.nodes[node].left = b;
+            self.update(node);
+            (a, node)
+        } else {
+            let right = self.nodes[node].right;
+            let (a, b) = self.split(right, count - self.size(left) - 1);
+            self.nodes[node].right = a;
+            self.update(node);
+            (node, b)
+        }
+    }
+
+    /// Join two subtrees, all of `a` coming before `b`.
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.nodes[a].priority > self.nodes[b].priority {
+            let right = self.nodes[a].right;
+            self.nodes[a].right = self.merge(right, b);
+            self.update(a);
+            a
+        } else {
+            let left = self.nodes[b].left;
+            self.nodes[b].left = self.merge(a, left);
+            self.update(b);
+            b
+        }
+    }
+}
+
+impl<T> Default for IndexedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for IndexedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = IndexedList::new();
+        for value in iter {
+            list.push(value);
+        }
+        list
+    }
+}
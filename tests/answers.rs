@@ -0,0 +1,99 @@
+//! Regression tests: run every day on its example and on the puzzle
+//! input, and compare with the answers stored next to them.
+//!
+//! The answers for `inputs/N.txt` live in `inputs/N.answers`, and those
+//! for `inputs/N-example.txt` in `inputs/N-example.answers`.  They are
+//! written the way the runner prints them, so a fresh answer file can be
+//! made with `cargo run --bin dayN > inputs/N.answers`.  A part without
+//! a stored answer is ignored.  Parameters are read from `.params`
+//! files, as the runner does.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+
+use libtest_mimic::{Arguments, Failed, Trial};
+
+use lib::days::{self, format_answer, Solver};
+use lib::input::Source;
+
+/// Both answers of a day, printed, or the error the solver gave.
+type Outcome = Result<[String; 2], String>;
+
+/// Split an answer file into the text of each part.
+fn read_answers(path: &PathBuf) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    let Ok(text) = fs::read_to_string(path) else {
+        return answers;
+    };
+
+    let mut current: Option<usize> = None;
+    for line in text.lines() {
+        let part = ["Part 1:", "Part 2:"]
+            .iter()
+            .position(|prefix| line.starts_with(prefix));
+        if let Some(part) = part {
+            current = Some(part);
+            answers[part] = Some(line.to_string());
+        } else if let Some(part) = current {
+            let answer = answers[part].as_mut().unwrap();
+            answer.push('\n');
+            answer.push_str(line);
+        }
+    }
+    answers
+}
+
+fn solve(solver: Solver, source: &Source) -> Outcome {
+    let input = source.read().map_err(|e| e.to_string())?;
+    let params = source.params().map_err(|e| e.to_string())?;
+    let solved = solver(&input, &params).map_err(|e| e.in_file(&source.to_string()).to_string())?;
+    Ok([format_answer(1, &solved.part1), format_answer(2, &solved.part2)])
+}
+
+/// The tests for one input of one day.  Both parts share a single run
+/// of the solver.
+fn trials(day: u8, solver: Solver, name: &str, source: Source) -> Vec<Trial> {
+    let Source::File(path) = &source else {
+        unreachable!()
+    };
+    if !path.exists() {
+        return vec![];
+    }
+    let answers = read_answers(&path.with_extension("answers"));
+    let outcome = Arc::new(OnceLock::<Outcome>::new());
+    let source = Arc::new(source);
+
+    answers
+        .into_iter()
+        .enumerate()
+        .map(|(part, expected)| {
+            let ignored = expected.is_none();
+            let outcome = outcome.clone();
+            let source = source.clone();
+            Trial::test(format!("day{day:02}::{name}::part{}", part + 1), move || {
+                let outcome = outcome.get_or_init(|| solve(solver, &source));
+                let answer = &outcome.as_ref().map_err(|e| Failed::from(e.as_str()))?[part];
+                let expected = expected.unwrap();
+                if answer.trim_end() == expected.trim_end() {
+                    Ok(())
+                } else {
+                    Err(format!("expected\n{expected}\ngot\n{answer}").into())
+                }
+            })
+            .with_ignored_flag(ignored)
+        })
+        .collect()
+}
+
+fn main() {
+    let args = Arguments::from_args();
+
+    let mut tests = vec![];
+    for &(day, solver) in days::DAYS {
+        tests.extend(trials(day, solver, "example", Source::example(day)));
+        tests.extend(trials(day, solver, "puzzle", Source::puzzle(day)));
+    }
+
+    libtest_mimic::run(&args, tests).exit();
+}